@@ -0,0 +1,173 @@
+//! implementation of `#[derive(ColorDebug)]`
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Field, Fields, Index, parse_quote};
+
+/// how a field is rendered according to its `#[color_debug(...)]` attribute
+enum Render {
+    Value,
+    Skip,
+    Redact,
+    Bytes,
+    Hex,
+}
+
+impl Render {
+    fn of(field: &Field) -> syn::Result<Render> {
+        let mut render = Render::Value;
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("color_debug"))
+        {
+            attr.parse_nested_meta(|meta| {
+                render = if meta.path.is_ident("skip") {
+                    Render::Skip
+                } else if meta.path.is_ident("redact") {
+                    Render::Redact
+                } else if meta.path.is_ident("bytes") {
+                    Render::Bytes
+                } else if meta.path.is_ident("hex") {
+                    Render::Hex
+                } else {
+                    return Err(meta.error(
+                        "unsupported color_debug option, expected one of: skip, redact, bytes, hex",
+                    ));
+                };
+                Ok(())
+            })?;
+        }
+        Ok(render)
+    }
+
+    /// expression rendering the given binding, [None] when skipped
+    fn expression(&self, binding: TokenStream) -> Option<TokenStream> {
+        let module = quote!(::debug_et_diagnostics::color_debug);
+        match self {
+            // nested values which derive ColorDebug keep their per-field colors
+            Render::Value => Some(quote! {{
+                #[allow(unused_imports)]
+                use #module::{ViaColorDebug as _, ViaDebug as _};
+                (&#module::Field(#binding)).render()
+            }}),
+            Render::Skip => None,
+            Render::Redact => Some(quote!(#module::redacted())),
+            Render::Bytes => Some(quote!(#module::debug_bytes(#binding))),
+            Render::Hex => Some(quote!(#module::debug_hex(#binding))),
+        }
+    }
+}
+
+/// renders the given fields, each bound to the given bindings
+fn render(name: &str, fields: &Fields, bindings: &[TokenStream]) -> syn::Result<TokenStream> {
+    let module = quote!(::debug_et_diagnostics::color_debug);
+    let mut calls = Vec::<TokenStream>::new();
+    for (field, binding) in fields.iter().zip(bindings) {
+        let Some(value) = Render::of(field)?.expression(binding.clone()) else {
+            continue;
+        };
+        calls.push(match &field.ident {
+            Some(ident) => {
+                let ident = ident.to_string();
+                let ident = ident.strip_prefix("r#").unwrap_or(&ident);
+                quote!(.field(#ident, #value))
+            }
+            None => quote!(.field(#value)),
+        });
+    }
+    Ok(match fields {
+        Fields::Named(_) => quote!(#module::DebugStruct::new(#name) #(#calls)* .finish()),
+        Fields::Unnamed(_) | Fields::Unit => {
+            quote!(#module::DebugTuple::new(#name) #(#calls)* .finish())
+        }
+    })
+}
+
+/// pattern destructuring the given fields into `field_0`, `field_1`, ...
+fn pattern(fields: &Fields) -> (TokenStream, Vec<TokenStream>) {
+    let bindings = (0..fields.len())
+        .map(|index| {
+            let binding = format_ident!("field_{}", index);
+            quote!(#binding)
+        })
+        .collect::<Vec<TokenStream>>();
+    let pattern = match fields {
+        Fields::Named(named) => {
+            let idents = named.named.iter().map(|field| &field.ident);
+            quote!({ #(#idents: #bindings),* })
+        }
+        Fields::Unnamed(_) => quote!(( #(#bindings),* )),
+        Fields::Unit => quote!(),
+    };
+    (pattern, bindings)
+}
+
+pub fn expand(input: TokenStream) -> syn::Result<TokenStream> {
+    let mut input = syn::parse2::<DeriveInput>(input)?;
+    let ident = &input.ident;
+    let name = ident.to_string();
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let bindings = match &data.fields {
+                Fields::Named(named) => named
+                    .named
+                    .iter()
+                    .map(|field| {
+                        let ident = &field.ident;
+                        quote!(&self.#ident)
+                    })
+                    .collect(),
+                fields => (0..fields.len())
+                    .map(|index| {
+                        let index = Index::from(index);
+                        quote!(&self.#index)
+                    })
+                    .collect::<Vec<TokenStream>>(),
+            };
+            render(&name, &data.fields, &bindings)?
+        }
+        Data::Enum(data) => {
+            let mut arms = Vec::<TokenStream>::new();
+            for variant in &data.variants {
+                let variant_ident = &variant.ident;
+                let (pattern, bindings) = pattern(&variant.fields);
+                let rendered = render(&variant_ident.to_string(), &variant.fields, &bindings)?;
+                arms.push(quote! {
+                    #[allow(unused_variables)]
+                    Self::#variant_ident #pattern => #rendered,
+                });
+            }
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "#[derive(ColorDebug)] does not support unions",
+            ));
+        }
+    };
+
+    let params = input
+        .generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect::<Vec<_>>();
+    let where_clause = input.generics.make_where_clause();
+    for param in params {
+        where_clause
+            .predicates
+            .push(parse_quote!(#param: ::std::fmt::Debug));
+    }
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::debug_et_diagnostics::color_debug::ColorDebug for #ident #type_generics #where_clause {
+            fn color_debug(&self) -> String {
+                #body
+            }
+        }
+    })
+}
@@ -0,0 +1,58 @@
+//! procedural macros of [debug-et-diagnostics](https://docs.rs/debug-et-diagnostics)
+use proc_macro::TokenStream;
+
+mod color_debug;
+mod trace;
+
+/// colorfully traces a function: prints [location!(begin)] followed by
+/// each argument rendered via [format_dbg!] on entry and the return value
+/// (or the `Err` of a `Result`) followed by [location!(end)] on exit.
+///
+/// options:
+///
+/// - `skip(arg, ...)`: does not print the given arguments (i.e.: `skip(self)`)
+/// - `skip_all`: does not print any argument
+/// - `display` or `display(arg, ...)`: renders all (or the given) arguments via [std::fmt::Display] rather than [std::fmt::Debug]
+/// - `skip_return`: does not print the return value
+///
+/// destructured arguments (i.e.: `(a, b): (u32, u32)`) are rendered as
+/// their pattern followed by their bindings.
+///
+/// the body is expanded inline, its `return`s (and its `?`s if it returns
+/// a `Result` or an `Option`) are rewritten so that the returned value is
+/// printed; those within other macros (i.e.: `anyhow::ensure!`) return
+/// without printing it, though the exit is still printed.
+///
+/// async functions are supported but do not change the call [depth]:
+/// it is thread-local while a future may be suspended and resumed on
+/// other threads, interleaved with other tasks.
+///
+/// [depth]: https://docs.rs/debug-et-diagnostics/latest/debug_et_diagnostics/scope/fn.depth.html
+/// [location!(begin)]: https://docs.rs/debug-et-diagnostics/latest/debug_et_diagnostics/macro.location.html
+/// [location!(end)]: https://docs.rs/debug-et-diagnostics/latest/debug_et_diagnostics/macro.location.html
+/// [format_dbg!]: https://docs.rs/debug-et-diagnostics/latest/debug_et_diagnostics/macro.format_dbg.html
+#[proc_macro_attribute]
+pub fn trace(attr: TokenStream, item: TokenStream) -> TokenStream {
+    trace::expand(attr.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// implements [ColorDebug] which renders field names with the theme's
+/// field color and each value with its own color.
+///
+/// fields accept the following attributes:
+///
+/// - `#[color_debug(skip)]`: hides the field
+/// - `#[color_debug(redact)]`: renders the field as `<redacted>`
+/// - `#[color_debug(bytes)]`: renders the field via [format_bytes_str!]
+/// - `#[color_debug(hex)]`: renders the field via `{:#x?}`
+///
+/// [ColorDebug]: https://docs.rs/debug-et-diagnostics/latest/debug_et_diagnostics/color_debug/trait.ColorDebug.html
+/// [format_bytes_str!]: https://docs.rs/debug-et-diagnostics/latest/debug_et_diagnostics/macro.format_bytes_str.html
+#[proc_macro_derive(ColorDebug, attributes(color_debug))]
+pub fn color_debug(input: TokenStream) -> TokenStream {
+    color_debug::expand(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
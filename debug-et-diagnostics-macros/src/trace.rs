@@ -0,0 +1,330 @@
+//! implementation of `#[trace]`
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::parse::Parser;
+use syn::visit::Visit;
+use syn::visit_mut::VisitMut;
+use syn::{
+    Expr, FnArg, Ident, Item, ItemFn, Lifetime, Pat, PatIdent, ReturnType, Type, TypeImplTrait,
+};
+
+/// options of `#[trace(...)]`
+#[derive(Default)]
+struct Options {
+    skip: Vec<Ident>,
+    skip_all: bool,
+    display: Vec<Ident>,
+    display_all: bool,
+    skip_return: bool,
+}
+
+impl Options {
+    fn parse(attr: TokenStream) -> syn::Result<Options> {
+        let mut options = Options::default();
+        let parser = syn::meta::parser(|meta| {
+            if meta.path.is_ident("skip") {
+                meta.parse_nested_meta(|meta| {
+                    options.skip.push(meta.path.require_ident()?.clone());
+                    Ok(())
+                })
+            } else if meta.path.is_ident("skip_all") {
+                options.skip_all = true;
+                Ok(())
+            } else if meta.path.is_ident("display") {
+                if meta.input.is_empty() || meta.input.peek(syn::Token![,]) {
+                    options.display_all = true;
+                    Ok(())
+                } else {
+                    meta.parse_nested_meta(|meta| {
+                        options.display.push(meta.path.require_ident()?.clone());
+                        Ok(())
+                    })
+                }
+            } else if meta.path.is_ident("skip_return") {
+                options.skip_return = true;
+                Ok(())
+            } else {
+                Err(meta.error(
+                    "unsupported trace option, expected one of: skip, skip_all, display, skip_return",
+                ))
+            }
+        });
+        parser.parse2(attr)?;
+        Ok(options)
+    }
+
+    fn skips(&self, ident: &Ident) -> bool {
+        self.skip_all || self.skip.contains(ident)
+    }
+
+    fn displays(&self, ident: &Ident) -> bool {
+        self.display_all || self.display.contains(ident)
+    }
+}
+
+/// whether the given type contains `impl Trait`, which cannot annotate closures or bindings
+fn contains_impl_trait(ty: &Type) -> bool {
+    struct Finder(bool);
+    impl<'ast> Visit<'ast> for Finder {
+        fn visit_type_impl_trait(&mut self, _: &'ast TypeImplTrait) {
+            self.0 = true;
+        }
+    }
+    let mut finder = Finder(false);
+    finder.visit_type(ty);
+    finder.0
+}
+
+/// whether the given type is (syntactically) a `Result`, i.e.: `Result<T, E>`, `io::Result<T>`
+fn is_result(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Result"),
+        Type::Group(group) => is_result(&group.elem),
+        Type::Paren(paren) => is_result(&paren.elem),
+        _ => false,
+    }
+}
+
+/// whether the given type is (syntactically) an `Option`
+fn is_option(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option"),
+        Type::Group(group) => is_option(&group.elem),
+        Type::Paren(paren) => is_option(&paren.elem),
+        _ => false,
+    }
+}
+
+/// identifiers bound by the given pattern, i.e.: `a` and `b` of `(a, Some(b))`
+fn bindings(pat: &Pat) -> Vec<Ident> {
+    struct Bindings(Vec<Ident>);
+    impl<'ast> Visit<'ast> for Bindings {
+        fn visit_pat_ident(&mut self, pat: &'ast PatIdent) {
+            self.0.push(pat.ident.clone());
+            syn::visit::visit_pat_ident(self, pat);
+        }
+    }
+    let mut bindings = Bindings(Vec::new());
+    bindings.visit_pat(pat);
+    bindings.0
+}
+
+/// what `?` propagates in the function, see [Returns]
+#[derive(Clone, Copy)]
+enum Propagates {
+    Err,
+    None,
+}
+
+/// rewrites the `return`s of a function body (and its `?`s if the function
+/// returns a `Result` or an `Option`) into `break`s out of the labeled block
+/// which wraps it, so that the returned value can be printed, without
+/// entering closures, async blocks or nested items whose `return`s are their own
+struct Returns {
+    label: Lifetime,
+    propagates: Option<Propagates>,
+}
+
+impl VisitMut for Returns {
+    fn visit_expr_mut(&mut self, expr: &mut Expr) {
+        let label = self.label.clone();
+        match expr {
+            Expr::Closure(_) | Expr::Async(_) | Expr::TryBlock(_) | Expr::Const(_) => {}
+            Expr::Return(ret) => {
+                if let Some(value) = &mut ret.expr {
+                    self.visit_expr_mut(value);
+                }
+                let attrs = &ret.attrs;
+                let value = &ret.expr;
+                *expr = Expr::Verbatim(quote!(#(#attrs)* break #label #value));
+            }
+            Expr::Try(question) if self.propagates.is_some() => {
+                self.visit_expr_mut(&mut question.expr);
+                let inner = &question.expr;
+                // verbatim since syn cannot parse `break 'label ::path`
+                *expr = Expr::Verbatim(match self.propagates {
+                    Some(Propagates::Err) => quote! {
+                        match #inner {
+                            ::core::result::Result::Ok(value) => value,
+                            ::core::result::Result::Err(error) => {
+                                break #label ::core::result::Result::Err(::core::convert::From::from(error))
+                            }
+                        }
+                    },
+                    _ => quote! {
+                        match #inner {
+                            ::core::option::Option::Some(value) => value,
+                            ::core::option::Option::None => break #label ::core::option::Option::None,
+                        }
+                    },
+                });
+            }
+            _ => syn::visit_mut::visit_expr_mut(self, expr),
+        }
+    }
+
+    fn visit_item_mut(&mut self, _: &mut Item) {}
+}
+
+fn is_unit(ty: &Type) -> bool {
+    matches!(ty, Type::Tuple(tuple) if tuple.elems.is_empty())
+}
+
+pub fn expand(attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    let options = Options::parse(attr)?;
+    let ItemFn {
+        attrs,
+        vis,
+        sig,
+        block,
+    } = syn::parse2::<ItemFn>(item)?;
+    if let Some(constness) = &sig.constness {
+        return Err(syn::Error::new_spanned(
+            constness,
+            "#[trace] does not support const functions",
+        ));
+    }
+
+    let arguments = sig
+        .inputs
+        .iter()
+        .filter_map(|input| match input {
+            FnArg::Receiver(receiver) => Some(Ident::new("self", receiver.self_token.span)),
+            FnArg::Typed(typed) => match &*typed.pat {
+                Pat::Ident(pat) => Some(pat.ident.clone()),
+                _ => None,
+            },
+        })
+        .filter(|ident| !options.skips(ident))
+        .map(|ident| {
+            if options.displays(&ident) {
+                quote! {
+                    ::debug_et_diagnostics::indent!(format!(
+                        "{} = {}",
+                        ::debug_et_diagnostics::color::auto(stringify!(#ident)),
+                        ::debug_et_diagnostics::color::auto(&#ident),
+                    ))
+                }
+            } else {
+                let name = ident.to_string();
+                quote!(::debug_et_diagnostics::format_dbg!(#name => #ident))
+            }
+        })
+        .collect::<Vec<TokenStream>>();
+    // destructured arguments are rendered as their pattern = their bindings
+    let patterns = sig
+        .inputs
+        .iter()
+        .filter_map(|input| match input {
+            FnArg::Typed(typed) if !matches!(&*typed.pat, Pat::Ident(_) | Pat::Wild(_)) => {
+                Some(&*typed.pat)
+            }
+            _ => None,
+        })
+        .filter_map(|pat| {
+            let bindings = bindings(pat);
+            if options.skip_all || bindings.iter().any(|ident| options.skips(ident)) {
+                return None;
+            }
+            let value = match bindings.as_slice() {
+                [ident] => quote!(&#ident),
+                _ => quote!(&(#(&#bindings),*)),
+            };
+            Some(quote! {
+                ::debug_et_diagnostics::format_dbg!(
+                    @labeled stringify!(#pat), #value, ::debug_et_diagnostics::limits::limits()
+                )
+            })
+        })
+        .collect::<Vec<TokenStream>>();
+
+    // the entry and the arguments are printed as a single block
+    let lines = quote!([#(#arguments,)* #(#patterns,)*]);
+
+    // mixed-site hygiene keeps these bindings from shadowing (or being shadowed by) arguments
+    let scope = Ident::new("scope", Span::mixed_site());
+    let returned = Ident::new("returned", Span::mixed_site());
+    let error = Ident::new("error", Span::mixed_site());
+    let output = match &sig.output {
+        ReturnType::Type(_, ty) if !contains_impl_trait(ty) => Some(ty),
+        _ => None,
+    };
+    let annotation = output.map(|ty| quote!(: #ty));
+    let never = matches!(&sig.output, ReturnType::Type(_, ty) if matches!(**ty, Type::Never(_)));
+    let (guard, body) = if sig.asyncness.is_some() {
+        (
+            quote!(::debug_et_diagnostics::scope::Scope::detached_with_lines),
+            quote! {
+                let #returned #annotation = async move #block.await;
+            },
+        )
+    } else if never {
+        (
+            quote!(::debug_et_diagnostics::scope::Scope::with_lines),
+            quote!(#block),
+        )
+    } else {
+        let label = Lifetime {
+            apostrophe: Span::mixed_site(),
+            ident: Ident::new("trace", Span::mixed_site()),
+        };
+        let mut block = block;
+        Returns {
+            label: label.clone(),
+            propagates: match &sig.output {
+                ReturnType::Type(_, ty) if is_result(ty) => Some(Propagates::Err),
+                ReturnType::Type(_, ty) if is_option(ty) => Some(Propagates::None),
+                _ => None,
+            },
+        }
+        .visit_block_mut(&mut block);
+        (
+            quote!(::debug_et_diagnostics::scope::Scope::with_lines),
+            quote! {
+                let #returned #annotation = #label: #block;
+            },
+        )
+    };
+    let print_returned = match &sig.output {
+        _ if options.skip_return => quote!(),
+        _ if never => quote!(),
+        ReturnType::Default => quote!(),
+        ReturnType::Type(_, ty) if is_unit(ty) => quote!(),
+        ReturnType::Type(_, ty) if is_result(ty) => quote! {
+            if let Err(#error) = &#returned {
+                ::debug_et_diagnostics::output::emit(::debug_et_diagnostics::format_dbg!("Err" => #error));
+            }
+        },
+        ReturnType::Type(..) => quote! {
+            ::debug_et_diagnostics::output::emit(::debug_et_diagnostics::format_dbg!("return" => #returned));
+        },
+    };
+
+    if never {
+        return Ok(quote! {
+            #(#attrs)*
+            #vis #sig {
+                let #scope = #guard(::debug_et_diagnostics::location!(), &#lines);
+                #body
+            }
+        });
+    }
+    Ok(quote! {
+        #(#attrs)*
+        #vis #sig {
+            let #scope = #guard(::debug_et_diagnostics::location!(), &#lines);
+            #body
+            #print_returned
+            ::core::mem::drop(#scope);
+            #returned
+        }
+    })
+}
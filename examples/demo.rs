@@ -1,7 +1,5 @@
 use debug_et_diagnostics::{from_debug, from_display, step, step_dbg};
 
-use std::env::args;
-
 fn main() {
     let args = get_argv();
     let args = if args.is_empty() {
@@ -14,7 +12,7 @@ fn main() {
         .into_iter()
         .enumerate()
         .filter(|(i, _)| *i > 0)
-        .map(|(_, c)| String::from(c))
+        .map(|(_, c)| c)
     {
         let fg = from_display(&arg) as usize;
         step!(fg = fg, format!("{arg}"));
@@ -24,5 +22,5 @@ fn main() {
 }
 
 fn get_argv() -> Vec<String> {
-    std::env::args().into_iter().collect::<Vec<String>>()
+    std::env::args().collect::<Vec<String>>()
 }
@@ -0,0 +1,39 @@
+//! command-line companion of the `debug-et-diagnostics` crate
+mod palette;
+mod pipe;
+
+use std::process::ExitCode;
+
+const USAGE: &str = "usage: debug-et-diagnostics <command> [arguments]
+
+commands:
+    palette                 prints the 256-color grid and what each color turns into
+    palette <index>...      prints the details of the given ANSI-256 colors
+    palette [-t] <text>...  shows how the given text is colored (-t/--text for numeric text)
+    hexdump [file]...       colorful hexdump of stdin or the given files
+    colorize [-t] [file]... colors each line (or each token with -t/--tokens) by its content
+    strip [file]...         removes ANSI escape sequences
+    byte [value]...         explains the given bytes (decimal, 0x hex, 0b binary or a character)";
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<String>>();
+    match args.first().map(String::as_str) {
+        Some("palette") => palette::run(&args[1..]),
+        Some("hexdump") => pipe::hexdump(&args[1..]),
+        Some("colorize") => pipe::colorize(&args[1..]),
+        Some("strip") => pipe::strip(&args[1..]),
+        Some("byte") => pipe::byte(&args[1..]),
+        Some("help" | "-h" | "--help") => {
+            println!("{USAGE}");
+            ExitCode::SUCCESS
+        }
+        Some(command) => {
+            eprintln!("unknown command: {command:#?}\n\n{USAGE}");
+            ExitCode::from(2)
+        }
+        None => {
+            eprintln!("{USAGE}");
+            ExitCode::from(2)
+        }
+    }
+}
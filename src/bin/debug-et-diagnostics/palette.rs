@@ -0,0 +1,117 @@
+use debug_et_diagnostics::{
+    ansi, auto, bright, color_name, dark, fg_rgb, from_debug, from_display, get_ansi_rgb,
+    invert_ansi, invert_bw, reset, rgb_from_display, rgb_to_byte, rgb_to_oklab, truecolor,
+};
+use std::process::ExitCode;
+
+/// prints the 256-color grid, the details of the given indices or how the
+/// given texts are colored, all arguments being texts with `-t`/`--text`
+pub fn run(args: &[String]) -> ExitCode {
+    let text = args.iter().any(|arg| arg == "-t" || arg == "--text");
+    let args = args
+        .iter()
+        .filter(|arg| *arg != "-t" && *arg != "--text")
+        .collect::<Vec<&String>>();
+    if args.is_empty() {
+        println!("{}\n", grid());
+        for color in 0..=u8::MAX {
+            println!("{}", details(color));
+        }
+    }
+    let mut entries = Vec::new();
+    for arg in args {
+        if text || !is_integer(arg) {
+            entries.push(format!("{}\n", inspect(arg)));
+            continue;
+        }
+        match arg.parse::<u8>() {
+            Ok(color) => entries.push(details(color)),
+            Err(_) => {
+                eprintln!(
+                    "not a color index: {arg} (expected 0 to 255, or --text {arg} to inspect it as text)"
+                );
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    for entry in entries {
+        println!("{entry}");
+    }
+    ExitCode::SUCCESS
+}
+
+fn is_integer(arg: &str) -> bool {
+    let digits = arg.strip_prefix('-').unwrap_or(arg);
+    !digits.is_empty() && digits.bytes().all(|byte| byte.is_ascii_digit())
+}
+
+/// renders the given color as a cell labeled with its index in black or white
+fn swatch(color: u8) -> String {
+    let label = if rgb_to_oklab(get_ansi_rgb(color.into()))[0] > 0.6 {
+        16
+    } else {
+        231
+    };
+    ansi(format!(" {color:>3} "), label, color.into())
+}
+
+/// the 256-color grid: standard colors, the 6x6x6 cube and the grayscale ramp
+fn grid() -> String {
+    let rows = [(0u8..16).collect::<Vec<u8>>()]
+        .into_iter()
+        .chain((0..12).map(|row| (16 + row * 18..16 + (row + 1) * 18).collect()))
+        .chain([(232..=u8::MAX).collect()]);
+    rows.map(|row| row.into_iter().map(swatch).collect::<String>())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn hex(rgb: [u8; 3]) -> String {
+    format!("#{:02x}{:02x}{:02x}", rgb[0], rgb[1], rgb[2])
+}
+
+/// index, RGB and what [invert_ansi], [invert_bw], [bright] and [dark] produce for the given color
+fn details(color: u8) -> String {
+    let rgb = get_ansi_rgb(color.into());
+    [
+        format!(
+            "{} {} {:<20}",
+            swatch(color),
+            hex(rgb),
+            color_name(color.into())
+        ),
+        format!("invert_ansi {}", swatch(invert_ansi(color.into()))),
+        format!("invert_bw {}", swatch(invert_bw(color.into()))),
+        format!("bright {}", swatch(bright(color.into()))),
+        format!("dark {}", swatch(dark(color.into()))),
+    ]
+    .join("  ")
+}
+
+/// shows how [from_display], [from_debug] and [rgb_from_display] color the given text
+fn inspect(text: &str) -> String {
+    let rgb = rgb_from_display(text);
+    let mut lines = vec![
+        auto(text),
+        format!(
+            "    from_display     {} {}",
+            swatch(from_display(text)),
+            color_name(from_display(text).into())
+        ),
+        format!(
+            "    from_debug       {} {}",
+            swatch(from_debug(text)),
+            color_name(from_debug(text).into())
+        ),
+        format!(
+            "    rgb_from_display {} {:?} => rgb_to_byte {}",
+            hex(rgb),
+            rgb,
+            swatch(rgb_to_byte(rgb))
+        ),
+    ];
+    if truecolor() {
+        lines.push(format!("    truecolor        {}", reset(fg_rgb(text, rgb))));
+    }
+    lines.join("\n")
+}
@@ -0,0 +1,186 @@
+use debug_et_diagnostics::{auto, format_byte, format_bytes, strip_ansi};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::process::ExitCode;
+
+/// opens the given files, or stdin when none (or `-`) is given
+fn inputs(paths: &[String]) -> io::Result<Vec<Box<dyn BufRead>>> {
+    if paths.is_empty() {
+        return Ok(vec![Box::new(BufReader::new(io::stdin()))]);
+    }
+    paths
+        .iter()
+        .map(|path| -> io::Result<Box<dyn BufRead>> {
+            if path == "-" {
+                Ok(Box::new(BufReader::new(io::stdin())))
+            } else {
+                Ok(Box::new(BufReader::new(File::open(path)?)))
+            }
+        })
+        .collect()
+}
+
+/// runs the given subcommand, treating a closed stdout (i.e.: `| head`) as success
+fn finish(result: io::Result<()>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) if error.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// colorful hexdump of stdin or the given files
+pub fn hexdump(paths: &[String]) -> ExitCode {
+    finish((|| {
+        let mut stdout = io::stdout().lock();
+        for mut input in inputs(paths)? {
+            let mut offset = 0;
+            let mut chunk = [0u8; 16];
+            loop {
+                let count = read_chunk(&mut input, &mut chunk)?;
+                if count == 0 {
+                    break;
+                }
+                writeln!(stdout, "{}", format_bytes!(dump, &chunk[..count], offset))?;
+                offset += count;
+            }
+        }
+        Ok(())
+    })())
+}
+
+fn read_chunk(input: &mut impl Read, chunk: &mut [u8]) -> io::Result<usize> {
+    let mut count = 0;
+    while count < chunk.len() {
+        match input.read(&mut chunk[count..])? {
+            0 => break,
+            read => count += read,
+        }
+    }
+    Ok(count)
+}
+
+/// reads the next line without its line ending, replacing invalid
+/// UTF-8 with `U+FFFD` rather than failing (i.e.: on binary input)
+fn read_line(input: &mut impl BufRead) -> io::Result<Option<String>> {
+    let mut bytes = Vec::new();
+    if input.read_until(b'\n', &mut bytes)? == 0 {
+        return Ok(None);
+    }
+    if bytes.ends_with(b"\n") {
+        bytes.pop();
+        if bytes.ends_with(b"\r") {
+            bytes.pop();
+        }
+    }
+    Ok(Some(String::from_utf8_lossy(&bytes).into_owned()))
+}
+
+/// colors each line, or each whitespace-separated token with `--tokens`, via [auto]
+pub fn colorize(args: &[String]) -> ExitCode {
+    let tokens = args.iter().any(|arg| arg == "-t" || arg == "--tokens");
+    let paths = args
+        .iter()
+        .filter(|arg| *arg != "-t" && *arg != "--tokens")
+        .cloned()
+        .collect::<Vec<String>>();
+    finish((|| {
+        let mut stdout = io::stdout().lock();
+        for mut input in inputs(&paths)? {
+            while let Some(line) = read_line(&mut input)? {
+                let line = strip_ansi(line);
+                if tokens {
+                    writeln!(stdout, "{}", colorize_tokens(&line))?;
+                } else if line.is_empty() {
+                    writeln!(stdout)?;
+                } else {
+                    writeln!(stdout, "{}", auto(line))?;
+                }
+                stdout.flush()?;
+            }
+        }
+        Ok(())
+    })())
+}
+
+/// colors each whitespace-separated token of the given line via [auto], preserving whitespace
+fn colorize_tokens(line: &str) -> String {
+    let mut result = String::new();
+    let mut token = String::new();
+    for c in line.chars() {
+        if c.is_whitespace() {
+            if !token.is_empty() {
+                result.push_str(&auto(&token));
+                token.clear();
+            }
+            result.push(c);
+        } else {
+            token.push(c);
+        }
+    }
+    if !token.is_empty() {
+        result.push_str(&auto(&token));
+    }
+    result
+}
+
+/// removes ANSI escape sequences from stdin or the given files
+pub fn strip(paths: &[String]) -> ExitCode {
+    finish((|| {
+        let mut stdout = io::stdout().lock();
+        for mut input in inputs(paths)? {
+            while let Some(line) = read_line(&mut input)? {
+                writeln!(stdout, "{}", strip_ansi(line))?;
+                stdout.flush()?;
+            }
+        }
+        Ok(())
+    })())
+}
+
+/// explains the given values (decimal, `0x` hex, `0b` binary or a single character)
+/// via [format_byte], reading whitespace-separated values from stdin when none is given
+pub fn byte(args: &[String]) -> ExitCode {
+    finish((|| {
+        let mut stdout = io::stdout().lock();
+        let values = if args.is_empty() {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            input.split_whitespace().map(String::from).collect()
+        } else {
+            args.to_vec()
+        };
+        for value in values {
+            match parse_byte(&value) {
+                Some(byte) => writeln!(stdout, "{}", format_byte!(byte))?,
+                None => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("not a byte: {value:#?}"),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    })())
+}
+
+fn parse_byte(value: &str) -> Option<u8> {
+    let lowercase = value.to_lowercase();
+    if let Some(hex) = lowercase.strip_prefix("0x") {
+        u8::from_str_radix(hex, 16).ok()
+    } else if let Some(bin) = lowercase.strip_prefix("0b") {
+        u8::from_str_radix(bin, 2).ok()
+    } else if let Ok(byte) = value.parse::<u8>() {
+        Some(byte)
+    } else {
+        let mut chars = value.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => u8::try_from(c).ok(),
+            _ => None,
+        }
+    }
+}
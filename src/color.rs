@@ -1,11 +1,17 @@
 use ansi_colours::{ansi256_from_rgb, rgb_from_ansi256};
 use std::fmt::{Debug, Display, LowerHex};
 use std::iter::{IntoIterator, Iterator};
+use std::sync::atomic::{AtomicU8, Ordering};
 
-#[cfg(feature = "colorsys")]
-use colorsys::{Ansi256, ColorAlpha, ColorTransform, Hsl, Rgb};
+use crate::cvd::cvd_safe_color;
+use crate::space::{oklab_to_oklch, oklab_to_rgb, oklch_to_rgb, rgb_to_oklab};
 
 const DEFAULT_COLUMNS: usize = 130;
+const COLOR_DEFAULT: u8 = 0;
+const COLOR_ON: u8 = 1;
+const COLOR_OFF: u8 = 2;
+
+static COLOR: AtomicU8 = AtomicU8::new(COLOR_DEFAULT);
 
 /// reset the ANSI colors of the given test
 pub fn reset<T: Display>(text: T) -> String {
@@ -20,6 +26,40 @@ pub fn fg<T: Display>(text: T, fg: usize) -> String {
 pub fn bg<T: Display>(text: T, bg: usize) -> String {
     format!("\x1b[1;48;5;{}m{}", wrap(bg), text)
 }
+/// colorize the foreground of the given text without resetting (24-bit colors)
+pub fn fg_rgb<T: Display>(text: T, rgb: [u8; 3]) -> String {
+    format!("\x1b[1;38;2;{};{};{}m{}", rgb[0], rgb[1], rgb[2], text)
+}
+/// colorize the background of the given text without resetting (24-bit colors)
+pub fn bg_rgb<T: Display>(text: T, rgb: [u8; 3]) -> String {
+    format!("\x1b[1;48;2;{};{};{}m{}", rgb[0], rgb[1], rgb[2], text)
+}
+/// returns true if the terminal advertises 24-bit color support via the `COLORTERM` environment variable
+pub fn truecolor() -> bool {
+    std::env::var("COLORTERM")
+        .map(|term| matches!(term.to_lowercase().as_str(), "truecolor" | "24bit"))
+        .unwrap_or_default()
+}
+/// forces colored output on or off, [None] restores the default which honors `NO_COLOR`
+pub fn set_color(enabled: Option<bool>) {
+    COLOR.store(
+        match enabled {
+            None => COLOR_DEFAULT,
+            Some(true) => COLOR_ON,
+            Some(false) => COLOR_OFF,
+        },
+        Ordering::Relaxed,
+    );
+}
+/// returns false if disabled via [set_color] or, by default, if the `NO_COLOR`
+/// environment variable is set to a non-empty value
+pub fn color_enabled() -> bool {
+    match COLOR.load(Ordering::Relaxed) {
+        COLOR_ON => true,
+        COLOR_OFF => false,
+        _ => std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty()),
+    }
+}
 /// colorize the foreground and backrground of the given text without resetting
 ///
 /// > shorthand combination of [bg] and [fg]
@@ -28,7 +68,7 @@ pub fn bgfg<T: Display>(text: T, fore: usize, back: usize) -> String {
 }
 /// colorize the foreground and backrground of the given text and reset the colors afterwards
 pub fn ansi<T: Display>(text: T, fore: usize, back: usize) -> String {
-    reset(bgfg(text, fore as usize, back as usize))
+    reset(bgfg(text, fore, back))
 }
 /// pad text by the number of columns determined by [term_cols]
 pub fn pad_columns<T: Display>(text: T) -> String {
@@ -36,14 +76,14 @@ pub fn pad_columns<T: Display>(text: T) -> String {
     let cols = term_cols();
     pad(text, cols)
 }
-/// pad text
+/// pad text, ignoring ANSI escape sequences (i.e.: colors, hyperlinks)
 pub fn pad<T: Display>(text: T, length: usize) -> String {
     let text = text.to_string();
-    let len = text
+    let len = strip_ansi(&text)
         .as_bytes()
         .iter()
         .map(|c| char::from(*c))
-        .map(|c| {
+        .flat_map(|c| {
             u32::from(c)
                 .to_ne_bytes()
                 .iter()
@@ -51,25 +91,48 @@ pub fn pad<T: Display>(text: T, length: usize) -> String {
                 .filter(|c| *c > 0)
                 .collect::<Vec<u8>>()
         })
-        .flatten()
         .count();
 
-    format!(
-        "{}{}",
-        text,
-        " ".repeat(if length > len {
-            length - len
-        } else if len < length {
-            0
-        } else {
-            0
-        })
-    )
+    format!("{}{}", text, " ".repeat(length.saturating_sub(len)))
 }
 /// clear the screen
 pub fn ansi_clear() -> String {
     "\x1b[2J\x1b[3J\x1b[H".to_string()
 }
+/// removes ANSI escape sequences (i.e.: colors, hyperlinks) from the given text
+pub fn strip_ansi<T: Display>(text: T) -> String {
+    let text = text.to_string();
+    let mut result = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if ('\x40'..='\x7e').contains(&c) {
+                        break;
+                    }
+                }
+            }
+            Some(']') => {
+                while let Some(c) = chars.next() {
+                    if c == '\x07' {
+                        break;
+                    }
+                    if c == '\x1b' {
+                        chars.next();
+                        break;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    result
+}
 /// colorize the foreground of the given text and reset afterwards
 pub fn fore<T: Display>(text: T, fore: usize) -> String {
     let (fore, back) = couple(fore);
@@ -84,7 +147,9 @@ pub fn back<T: Display>(text: T, back: usize) -> String {
 pub fn auto<T: Display>(word: T) -> String {
     fore(
         word.to_string(),
-        u8::from_str_radix(&word.to_string(), 10)
+        word.to_string()
+            .parse::<u8>()
+            .map(|color| cvd_safe_color(color.into()))
             .unwrap_or_else(|_| from_display(word.to_string()))
             .into(),
     )
@@ -94,7 +159,9 @@ pub fn auto_bright<T: Display>(word: T) -> String {
     fore(
         word.to_string(),
         bright(
-            u8::from_str_radix(&word.to_string(), 10)
+            word.to_string()
+                .parse::<u8>()
+                .map(|color| cvd_safe_color(color.into()))
                 .unwrap_or_else(|_| from_display(word.to_string()))
                 .into(),
         )
@@ -106,7 +173,9 @@ pub fn auto_dark<T: Display>(word: T) -> String {
     fore(
         word.to_string(),
         dark(
-            u8::from_str_radix(&word.to_string(), 10)
+            word.to_string()
+                .parse::<u8>()
+                .map(|color| cvd_safe_color(color.into()))
                 .unwrap_or_else(|_| from_display(word.to_string()))
                 .into(),
         )
@@ -114,10 +183,13 @@ pub fn auto_dark<T: Display>(word: T) -> String {
     )
 }
 /// auto-colorize the underlying bytes of given text with the color determined by [from_bytes]
+///
+/// > restricted to the [cvd_safe_palette](crate::cvd::cvd_safe_palette) when set via [set_cvd_safe](crate::cvd::set_cvd_safe)
 pub fn from_display<T: Display>(word: T) -> u8 {
     let string = format!("{word}");
-    from_bytes(
-        &u8::from_str_radix(&string, 10)
+    cvd_safe_color(from_bytes(
+        &string
+            .parse::<u8>()
             .ok()
             .or_else(|| u8::from_str_radix(&string, 16).ok())
             .map(|byte| vec![byte])
@@ -174,11 +246,11 @@ pub fn from_display<T: Display>(word: T) -> u8 {
                 }
             })
             .unwrap_or_else(|| string.as_bytes().to_vec()),
-    )
+    ) as usize)
 }
 /// auto-colorize the underlying bytes of given text with the color determined by [from_bytes]
 pub fn from_debug<T: Debug>(word: T) -> u8 {
-    from_bytes(format!("{word:#?}").as_bytes())
+    cvd_safe_color(from_bytes(format!("{word:#?}").as_bytes()) as usize)
 }
 /// determine a triple of RGB colors of a string determined by [rgb_from_bytes]
 pub fn rgb_from_display<T: Display>(word: T) -> [u8; 3] {
@@ -196,7 +268,7 @@ pub fn from_bytes(bytes: &[u8]) -> u8 {
 /// simple and naive algorithm to determine a triple of RGB colors
 /// based on XOR'ing the given slice of bytes;
 pub fn rgb_from_bytes(bytes: &[u8]) -> [u8; 3] {
-    merge_rgb(bytes.into_iter().map(|byte| rgb_from_byte(*byte)), false)
+    merge_rgb(bytes.iter().map(|byte| rgb_from_byte(*byte)), false)
 }
 /// returns a `[red, green, blue]` slice `[u8; 3]` from a single byte
 pub fn rgb_from_byte(byte: u8) -> [u8; 3] {
@@ -239,57 +311,22 @@ pub fn couple(color: usize) -> (u8, u8) {
     (fore, back)
 }
 
-/// converts the given color to rgb triple then inverts the rgb and converts back to ansi256
-#[cfg(feature = "colorsys")]
+/// converts the given color to OKLab, inverts its lightness and hue
+/// (i.e.: the perceptual counterpart of [invert_rgb]) and converts back to ansi256
 pub fn invert_ansi(color: usize) -> u8 {
-    let color = Ansi256::new(wrap(color));
-    let mut hsl = Hsl::from(&Rgb::from(color));
-    hsl.set_lightness(100.0 - hsl.lightness());
-    let mut rgb = Rgb::from(&hsl);
-    rgb.invert();
-    let color = Ansi256::from(&rgb);
-    color.code()
-}
-/// converts the given color to rgb triple then inverts the rgb and converts back to ansi256
-#[cfg(not(feature = "colorsys"))]
-pub fn invert_ansi(color: usize) -> u8 {
-    if is_dark_rgb_band(color) {
-        bright(rgb_to_byte(invert_rgb(rgb_from_byte(wrap(color)))) as usize)
-    } else {
-        dark(rgb_to_byte(invert_rgb(rgb_from_byte(wrap(color)))) as usize)
-    }
+    let [lightness, a, b] = rgb_to_oklab(get_ansi_rgb(color));
+    rgb_to_byte(oklch_to_rgb(oklab_to_oklch([1.0 - lightness, -a, -b])))
 }
 
-/// converts the given color to rgb triple then inverts the rgb and converts back to ansi256
-#[cfg(feature = "colorsys")]
-pub fn invert_rgb(color: [u8; 3]) -> [u8; 3] {
-    let mut rgb = Rgb::from(color);
-    rgb.invert();
-    rgb.into()
-}
-
-/// converts the given color to rgb triple then inverts the rgb and converts back to ansi256
-#[cfg(not(feature = "colorsys"))]
+/// inverts the given `[red, green, blue]` triple
 pub fn invert_rgb(color: [u8; 3]) -> [u8; 3] {
     [255u8 - color[0], 255u8 - color[1], 255u8 - color[2]]
 }
 
-/// naive heuristic to return the brightest opposite of the given color.
-#[cfg(not(feature = "colorsys"))]
-pub fn invert_bw(color: usize) -> u8 {
-    match color {
-        0 | 8 | 16..21 | 52..61 | 88..93 | 232..239 => 231,
-        _ => 16,
-    }
-}
-/// brings the color to grayscale then inverts it
-#[cfg(feature = "colorsys")]
+/// brings the color to grayscale (its OKLab lightness) then inverts it
 pub fn invert_bw(color: usize) -> u8 {
-    let color = Ansi256::new(wrap(color));
-    let mut rgb = Rgb::from(color);
-    rgb.grayscale_simple();
-    rgb.invert();
-    Ansi256::from(&rgb).code()
+    let [lightness, _, _] = rgb_to_oklab(get_ansi_rgb(color));
+    rgb_to_byte(oklab_to_rgb([1.0 - lightness, 0.0, 0.0]))
 }
 
 /// return true if the given rgb band is bright
@@ -305,24 +342,9 @@ pub fn bright_rgb_band(color: usize) -> u8 {
         wrap(color)
     }
 }
-/// return a brighter color near the given one via [bright_rgb_band].
-#[cfg(feature = "colorsys")]
+/// return a brighter color near the given one by increasing its OKLab lightness
 pub fn bright(color: usize) -> u8 {
-    let color = wrap(color);
-    let mut rgb = Rgb::from(Ansi256::new(color));
-    rgb.lighten(50.0);
-    Ansi256::from(rgb).code()
-}
-/// return a brighter color near the given one via [bright_rgb_band].
-#[cfg(not(feature = "colorsys"))]
-pub fn bright(color: usize) -> u8 {
-    let color = wrap(color);
-    let [r, g, b] = rgb_from_byte(color);
-    rgb_to_byte([
-        bright_rgb_band(r as usize),
-        bright_rgb_band(g as usize),
-        bright_rgb_band(b as usize),
-    ])
+    lighten(color, 50.0)
 }
 /// return true if the given rgb band is dark
 pub fn is_dark_rgb_band(color: usize) -> bool {
@@ -338,23 +360,23 @@ pub fn dark_rgb_band(color: usize) -> u8 {
         color
     }
 }
-/// return a darker color near the given one via [dark_rgb_band].
-#[cfg(feature = "colorsys")]
+/// return a darker color near the given one by decreasing its OKLab lightness
 pub fn dark(color: usize) -> u8 {
-    let color = wrap(color);
-    let mut rgb = Rgb::from(Ansi256::new(color));
-    rgb.lighten(-50.0);
-    Ansi256::from(rgb).code()
+    lighten(color, -50.0)
 }
-#[cfg(not(feature = "colorsys"))]
-pub fn dark(color: usize) -> u8 {
-    let color = wrap(color);
-    let [r, g, b] = rgb_from_byte(color);
-    rgb_to_byte([
-        dark_rgb_band(r as usize),
-        dark_rgb_band(g as usize),
-        dark_rgb_band(b as usize),
-    ])
+
+/// moves the OKLab lightness of the given color the given percentage of
+/// the way towards white (or black when negative), keeping its chroma and
+/// hue as far as the sRGB gamut allows
+pub fn lighten(color: usize, amount: f64) -> u8 {
+    let [lightness, chroma, hue] = oklab_to_oklch(rgb_to_oklab(get_ansi_rgb(color)));
+    let amount = (amount / 100.0).clamp(-1.0, 1.0);
+    let lightness = if amount < 0.0 {
+        lightness * (1.0 + amount)
+    } else {
+        lightness + (1.0 - lightness) * amount
+    };
+    rgb_to_byte(oklch_to_rgb([lightness, chroma, hue]))
 }
 
 /// wraps the given usize via remainder
@@ -0,0 +1,171 @@
+//! field-aware colorful rendering of structs and enums, see [ColorDebug]
+use crate::color::{fore, from_debug, from_display};
+use crate::theme::theme;
+use std::fmt::Debug;
+
+/// colorful alternative to `{:#?}` usually implemented via `#[derive(ColorDebug)]`
+/// which renders field names with the [Theme::field](crate::theme::Theme::field)
+/// color and each value with its own color determined by [from_debug].
+///
+/// fields accept the following attributes:
+///
+/// - `#[color_debug(skip)]`: hides the field
+/// - `#[color_debug(redact)]`: renders the field as `<redacted>`
+/// - `#[color_debug(bytes)]`: renders the field via [format_bytes_str](crate::format_bytes_str)
+/// - `#[color_debug(hex)]`: renders the field via `{:#x?}`
+pub trait ColorDebug {
+    /// colorful multi-line rendering of `self`
+    fn color_debug(&self) -> String;
+}
+
+fn punctuation(text: &str) -> String {
+    fore(text, theme().punctuation.into())
+}
+
+/// indents all lines but the first of a rendered value
+fn nested(value: &str) -> String {
+    value.replace('\n', "\n    ")
+}
+
+/// renders a value via `{:#?}` colored by [from_debug]
+pub fn debug_value<T: Debug + ?Sized>(value: &T) -> String {
+    let color = from_debug(value);
+    format!("{value:#?}")
+        .lines()
+        .map(|line| fore(line, color.into()))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// wraps a field so that `#[derive(ColorDebug)]` renders it via its own
+/// [ColorDebug] implementation if any (see [ViaColorDebug]) or else via
+/// [debug_value] (see [ViaDebug]), i.e.: `(&Field(&value)).render()`
+#[doc(hidden)]
+pub struct Field<'a, T: ?Sized>(pub &'a T);
+
+/// renders a [Field] via [ColorDebug], preferred over [ViaDebug] by method resolution
+#[doc(hidden)]
+pub trait ViaColorDebug {
+    fn render(&self) -> String;
+}
+
+impl<T: ColorDebug + ?Sized> ViaColorDebug for Field<'_, T> {
+    fn render(&self) -> String {
+        self.0.color_debug()
+    }
+}
+
+/// renders a [Field] via [debug_value] when it does not implement [ColorDebug]
+#[doc(hidden)]
+pub trait ViaDebug {
+    fn render(&self) -> String;
+}
+
+impl<T: Debug + ?Sized> ViaDebug for &Field<'_, T> {
+    fn render(&self) -> String {
+        debug_value(self.0)
+    }
+}
+
+/// renders a value via `{:#x?}` colored by [from_display]
+pub fn debug_hex<T: Debug + ?Sized>(value: &T) -> String {
+    let text = format!("{value:#x?}");
+    let color = from_display(&text);
+    text.lines()
+        .map(|line| fore(line, color.into()))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// renders bytes via [format_bytes_str](crate::format_bytes_str)
+pub fn debug_bytes<T: AsRef<[u8]> + ?Sized>(value: &T) -> String {
+    crate::format_bytes_str!(value.as_ref())
+}
+
+/// renders a hidden value
+pub fn redacted() -> String {
+    fore("<redacted>", theme().redacted.into())
+}
+
+/// colorful alternative to [std::fmt::DebugStruct]
+#[derive(Debug, Clone)]
+pub struct DebugStruct {
+    name: String,
+    fields: Vec<(String, String)>,
+}
+
+impl DebugStruct {
+    /// starts rendering a struct (or struct-like enum variant) with the given name
+    pub fn new(name: &str) -> DebugStruct {
+        DebugStruct {
+            name: name.to_string(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// adds a field with the given name and rendered value
+    pub fn field(mut self, name: &str, value: String) -> DebugStruct {
+        self.fields.push((name.to_string(), value));
+        self
+    }
+
+    /// renders the struct like `{:#?}`
+    pub fn finish(self) -> String {
+        let name = fore(&self.name, theme().type_name.into());
+        if self.fields.is_empty() {
+            return name;
+        }
+        let theme = theme();
+        let mut lines = vec![format!("{} {}", name, punctuation("{"))];
+        lines.extend(self.fields.iter().map(|(field, value)| {
+            format!(
+                "    {}{} {}{}",
+                fore(field, theme.field.into()),
+                punctuation(":"),
+                nested(value),
+                punctuation(",")
+            )
+        }));
+        lines.push(punctuation("}"));
+        lines.join("\n")
+    }
+}
+
+/// colorful alternative to [std::fmt::DebugTuple]
+#[derive(Debug, Clone)]
+pub struct DebugTuple {
+    name: String,
+    fields: Vec<String>,
+}
+
+impl DebugTuple {
+    /// starts rendering a tuple struct (or tuple-like enum variant) with the given name
+    pub fn new(name: &str) -> DebugTuple {
+        DebugTuple {
+            name: name.to_string(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// adds a field with the given rendered value
+    pub fn field(mut self, value: String) -> DebugTuple {
+        self.fields.push(value);
+        self
+    }
+
+    /// renders the tuple like `{:#?}`
+    pub fn finish(self) -> String {
+        let name = fore(&self.name, theme().type_name.into());
+        if self.fields.is_empty() {
+            return name;
+        }
+        let mut lines = vec![format!("{}{}", name, punctuation("("))];
+        lines.extend(
+            self.fields
+                .iter()
+                .map(|value| format!("    {}{}", nested(value), punctuation(","))),
+        );
+        lines.push(punctuation(")"));
+        lines.join("\n")
+    }
+}
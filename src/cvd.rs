@@ -0,0 +1,249 @@
+//! color-vision-deficiency simulation and cvd-safe color picking
+use crate::color::{get_ansi_rgb, rgb_to_byte, wrap};
+use crate::space::{from_linear, oklab_distance, to_linear};
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// minimum OKLab distance (black and white being 1.0 apart) between two
+/// simulated colors of [cvd_safe_palette]
+const MIN_DISTANCE: f64 = 0.1;
+
+static CVD_SAFE: AtomicU8 = AtomicU8::new(0);
+
+/// color-vision deficiencies supported by [simulate] and [set_cvd_safe]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Deficiency {
+    /// missing L-cones (red-blind)
+    Protanopia,
+    /// missing M-cones (green-blind)
+    Deuteranopia,
+    /// missing S-cones (blue-blind)
+    Tritanopia,
+}
+
+impl Deficiency {
+    /// all supported deficiencies
+    pub const ALL: [Deficiency; 3] = [
+        Deficiency::Protanopia,
+        Deficiency::Deuteranopia,
+        Deficiency::Tritanopia,
+    ];
+
+    /// simulation matrix in linear RGB (Machado, Oliveira & Fernandes 2009, severity 1.0)
+    fn matrix(self) -> [[f64; 3]; 3] {
+        match self {
+            Deficiency::Protanopia => [
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ],
+            Deficiency::Deuteranopia => [
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.011820, 0.042940, 0.968881],
+            ],
+            Deficiency::Tritanopia => [
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.303900],
+            ],
+        }
+    }
+
+    fn code(self) -> u8 {
+        match self {
+            Deficiency::Protanopia => 1,
+            Deficiency::Deuteranopia => 2,
+            Deficiency::Tritanopia => 3,
+        }
+    }
+
+    fn from_code(code: u8) -> Option<Deficiency> {
+        match code {
+            1 => Some(Deficiency::Protanopia),
+            2 => Some(Deficiency::Deuteranopia),
+            3 => Some(Deficiency::Tritanopia),
+            _ => None,
+        }
+    }
+}
+
+/// simulates how the given `[red, green, blue]` triple is perceived under the given deficiency
+pub fn simulate_rgb(rgb: [u8; 3], deficiency: Deficiency) -> [u8; 3] {
+    let linear = rgb.map(to_linear);
+    let matrix = deficiency.matrix();
+    let mut result = [0u8; 3];
+    for (channel, row) in result.iter_mut().zip(matrix.iter()) {
+        *channel = from_linear(row[0] * linear[0] + row[1] * linear[1] + row[2] * linear[2]);
+    }
+    result
+}
+
+/// simulates protanopia over the given `[red, green, blue]` triple
+pub fn protanopia(rgb: [u8; 3]) -> [u8; 3] {
+    simulate_rgb(rgb, Deficiency::Protanopia)
+}
+/// simulates deuteranopia over the given `[red, green, blue]` triple
+pub fn deuteranopia(rgb: [u8; 3]) -> [u8; 3] {
+    simulate_rgb(rgb, Deficiency::Deuteranopia)
+}
+/// simulates tritanopia over the given `[red, green, blue]` triple
+pub fn tritanopia(rgb: [u8; 3]) -> [u8; 3] {
+    simulate_rgb(rgb, Deficiency::Tritanopia)
+}
+
+/// returns the ANSI-256 color nearest to how the given color is perceived under the given deficiency
+pub fn simulate(color: usize, deficiency: Deficiency) -> u8 {
+    rgb_to_byte(simulate_rgb(get_ansi_rgb(color), deficiency))
+}
+
+/// euclidean distance between two `[red, green, blue]` triples
+pub fn rgb_distance(a: [u8; 3], b: [u8; 3]) -> f64 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(a, b)| (*a as f64 - *b as f64).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// ANSI-256 colors (16 through 255) which remain distinguishable from one
+/// another (at least [MIN_DISTANCE] apart in OKLab) under the given deficiency
+pub fn cvd_safe_palette(deficiency: Deficiency) -> &'static [u8] {
+    static PALETTES: [OnceLock<Vec<u8>>; 3] = [OnceLock::new(), OnceLock::new(), OnceLock::new()];
+    PALETTES[deficiency.code() as usize - 1].get_or_init(|| {
+        let mut palette = Vec::<u8>::new();
+        let mut simulated = Vec::<[u8; 3]>::new();
+        for color in 16..=u8::MAX {
+            let rgb = simulate_rgb(get_ansi_rgb(color as usize), deficiency);
+            if simulated
+                .iter()
+                .all(|other| oklab_distance(rgb, *other) >= MIN_DISTANCE)
+            {
+                palette.push(color);
+                simulated.push(rgb);
+            }
+        }
+        palette
+    })
+}
+
+/// restricts automatically picked colors (i.e.: [auto](crate::color::auto),
+/// [from_display](crate::color::from_display)) to the [cvd_safe_palette] of
+/// the given deficiency, [None] disables the restriction.
+pub fn set_cvd_safe(deficiency: Option<Deficiency>) {
+    CVD_SAFE.store(
+        deficiency.map(Deficiency::code).unwrap_or(0),
+        Ordering::Relaxed,
+    );
+}
+
+/// the deficiency currently set via [set_cvd_safe]
+pub fn cvd_safe() -> Option<Deficiency> {
+    Deficiency::from_code(CVD_SAFE.load(Ordering::Relaxed))
+}
+
+/// entry of the [cvd_safe_palette] of the given deficiency perceptually
+/// nearest (in OKLab) to the given color
+pub fn nearest_cvd_safe(color: usize, deficiency: Deficiency) -> u8 {
+    static NEAREST: [OnceLock<Vec<u8>>; 3] = [OnceLock::new(), OnceLock::new(), OnceLock::new()];
+    NEAREST[deficiency.code() as usize - 1].get_or_init(|| {
+        let palette = cvd_safe_palette(deficiency);
+        (0..=u8::MAX as usize)
+            .map(|color| {
+                let rgb = get_ansi_rgb(color);
+                *palette
+                    .iter()
+                    .min_by(|a, b| {
+                        oklab_distance(rgb, get_ansi_rgb(**a as usize))
+                            .total_cmp(&oklab_distance(rgb, get_ansi_rgb(**b as usize)))
+                    })
+                    .unwrap_or(&wrap(color))
+            })
+            .collect()
+    })[wrap(color) as usize]
+}
+
+/// maps the given color onto the nearest entry of the [cvd_safe_palette] of
+/// the deficiency set via [set_cvd_safe] or returns it unchanged.
+pub fn cvd_safe_color(color: usize) -> u8 {
+    match cvd_safe() {
+        Some(deficiency) => nearest_cvd_safe(color, deficiency),
+        None => wrap(color),
+    }
+}
+
+/// hands out one color per key (i.e.: thread, task or variable name), each
+/// as far as possible from the colors handed out before and restricted to
+/// the [cvd_safe_palette] when set via [set_cvd_safe]
+#[derive(Debug, Clone, Default)]
+pub struct ColorAllocator {
+    colors: BTreeMap<String, u8>,
+}
+
+impl ColorAllocator {
+    /// an allocator which has not handed out any color yet
+    pub fn new() -> ColorAllocator {
+        ColorAllocator::default()
+    }
+
+    /// the color of the given key, allocating one on first use: the
+    /// [from_display](crate::color::from_display) color of the first key,
+    /// then the candidate farthest from all allocated colors as perceived
+    /// under the deficiency set via [set_cvd_safe]
+    pub fn color<T: Display>(&mut self, key: T) -> u8 {
+        let key = key.to_string();
+        if let Some(color) = self.colors.get(&key) {
+            return *color;
+        }
+        let color = if self.colors.is_empty() {
+            crate::color::from_display(&key)
+        } else {
+            let deficiency = cvd_safe();
+            let perceived = |color: u8| match deficiency {
+                Some(deficiency) => simulate_rgb(get_ansi_rgb(color as usize), deficiency),
+                None => get_ansi_rgb(color as usize),
+            };
+            let allocated = self
+                .colors
+                .values()
+                .map(|color| perceived(*color))
+                .collect::<Vec<[u8; 3]>>();
+            let candidates = match deficiency {
+                Some(deficiency) => cvd_safe_palette(deficiency).to_vec(),
+                None => (16..=u8::MAX).collect(),
+            };
+            candidates
+                .into_iter()
+                .map(|color| {
+                    let rgb = perceived(color);
+                    let distance = allocated
+                        .iter()
+                        .map(|other| oklab_distance(rgb, *other))
+                        .fold(f64::INFINITY, f64::min);
+                    (color, distance)
+                })
+                .fold((0, f64::NEG_INFINITY), |best, candidate| {
+                    if candidate.1 > best.1 {
+                        candidate
+                    } else {
+                        best
+                    }
+                })
+                .0
+        };
+        self.colors.insert(key, color);
+        color
+    }
+
+    /// number of allocated colors
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// whether no color has been allocated
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+}
@@ -0,0 +1,171 @@
+//! function-based counterparts of [step](crate::step!), [info](crate::info!),
+//! [warn](crate::warn!) and [dbg](crate::dbg!) which report the location of
+//! their caller via `#[track_caller]`, i.e.: from within helper functions
+//!
+//! > source snippets require a [source_root](crate::snippet::set_source_root)
+//! > for files of the calling crate, see [set_source_snippets](crate::snippet::set_source_snippets)
+use crate::color::{ansi, auto, couple, invert_ansi, pad, reset};
+use crate::gradient::gradient_bg;
+use crate::hyperlink::link_location;
+use crate::limits::limits;
+use crate::location::{SourceLocation, format_begin, format_end, format_location, plain_location};
+use crate::meta::short_type_name;
+use crate::output::{columns, emit};
+use crate::snippet::{append_snippet, source_root};
+use crate::timing::format_step_header;
+use std::fmt::{Debug, Display};
+use std::panic::Location;
+use std::path::Path;
+
+/// renders the banner printed by [step](crate::step!) with the given header (i.e.: location)
+pub fn format_step(
+    header: &str,
+    text: &str,
+    fg: usize,
+    bg: usize,
+    length: usize,
+    gradient: bool,
+) -> String {
+    let bar = if gradient {
+        gradient_bg(" ".repeat(length), bg, invert_ansi(bg) as usize)
+    } else {
+        ansi(" ".repeat(length), fg, bg)
+    };
+    format!(
+        "\n{}",
+        [
+            bar.clone(),
+            format_step_header(header, fg, bg),
+            ansi(pad(text, columns()), bg, fg),
+            bar,
+        ]
+        .join("\n")
+    )
+}
+
+/// renders the admonition printed by [admonition](crate::admonition!) with
+/// the given header (i.e.: location and title)
+pub fn format_admonition(header: &str, message: &str, color: usize) -> String {
+    format!(
+        "\n{}",
+        [
+            ansi(header, invert_ansi(color).into(), color),
+            ansi(message, color, invert_ansi(color).into()),
+        ]
+        .join(" ")
+    )
+}
+
+/// renders the given lines between [location!(begin)](crate::location) and
+/// [location!(end)](crate::location) as printed by [dbg](crate::dbg!)
+pub fn format_dbg<T: Display>(location: T, lines: &[String]) -> String {
+    let location = location.to_string();
+    let mut block = vec![format_begin(&location)];
+    block.extend_from_slice(lines);
+    block.push(format_end(&location));
+    reset(block.join("\n"))
+}
+
+/// prints the given block followed by the snippet of the given caller (if
+/// enabled), see [append_snippet](crate::snippet::append_snippet)
+///
+/// > the `CARGO_MANIFEST_DIR` of the caller is unknown to `#[track_caller]`
+/// > functions, so snippets are only shown when its `file!()` is absolute
+/// > (i.e.: a dependency) or a [source_root](crate::snippet::source_root) is set
+fn emit_with_snippet(block: String, caller: &Location) {
+    if Path::new(caller.file()).is_absolute() || source_root().is_some() {
+        emit(append_snippet(
+            block,
+            "",
+            caller.file(),
+            caller.line(),
+            caller.column(),
+        ));
+    } else {
+        emit(block);
+    }
+}
+
+fn caller_admonition(caller: &Location, title: &str, message: String, color: usize) -> String {
+    let header = format!(
+        "{} {}",
+        plain_location(&SourceLocation::caller(caller)),
+        title
+    );
+    format_admonition(&header, &message, color)
+}
+
+/// renders the banner printed by [step] at the location of the caller
+#[track_caller]
+pub fn format_caller_step<T: Display>(text: T) -> String {
+    let caller = Location::caller();
+    let (bg, fg) = couple(caller.line() as usize);
+    let location = SourceLocation::caller(caller);
+    let header = link_location(plain_location(&location), &location);
+    format_step(
+        &header,
+        &text.to_string(),
+        fg as usize,
+        bg as usize,
+        columns(),
+        false,
+    )
+}
+
+/// renders the admonition printed by [info] at the location of the caller
+#[track_caller]
+pub fn format_caller_info<T: Display>(message: T) -> String {
+    caller_admonition(Location::caller(), "INFO", message.to_string(), 74)
+}
+
+/// renders the admonition printed by [warn] at the location of the caller
+#[track_caller]
+pub fn format_caller_warn<T: Display>(message: T) -> String {
+    caller_admonition(Location::caller(), "WARNING", message.to_string(), 220)
+}
+
+/// renders the value printed by [dbg] below a `<dbg at {file}:{line}>` tag
+/// with the location of the caller, labeled with its type name
+#[track_caller]
+pub fn format_caller_dbg<T: Debug + ?Sized>(value: &T) -> String {
+    let caller = Location::caller();
+    let label = short_type_name(std::any::type_name::<T>());
+    reset(
+        [
+            crate::tag!(
+                [
+                    auto("dbg at"),
+                    format_location(None, &SourceLocation::caller(caller))
+                ]
+                .join(" ")
+            ),
+            crate::format_dbg!(@labeled label, value, limits()),
+        ]
+        .join("\n"),
+    )
+}
+
+/// prints the banner of [step](crate::step!) at the location of the caller
+#[track_caller]
+pub fn step<T: Display>(text: T) {
+    emit_with_snippet(format_caller_step(text), Location::caller());
+}
+
+/// prints an "INFO" admonition as [info](crate::info!) at the location of the caller
+#[track_caller]
+pub fn info<T: Display>(message: T) {
+    emit_with_snippet(format_caller_info(message), Location::caller());
+}
+
+/// prints a "WARNING" admonition as [warn](crate::warn!) at the location of the caller
+#[track_caller]
+pub fn warn<T: Display>(message: T) {
+    emit_with_snippet(format_caller_warn(message), Location::caller());
+}
+
+/// prints the given value as [dbg](crate::dbg!) at the location of the
+/// caller, labeled with its type name, see [format_caller_dbg]
+#[track_caller]
+pub fn dbg<T: Debug + ?Sized>(value: &T) {
+    emit(format_caller_dbg(value));
+}
@@ -0,0 +1,183 @@
+//! colorful rendering of errors along with their chain of sources
+use crate::color::{auto, fore};
+use crate::limits::limits;
+use crate::output::{INDENTATION, indent_lines};
+use crate::tap::emit_tap;
+#[cfg(feature = "backtrace")]
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::error::Error;
+use std::fmt::Debug;
+use std::panic::Location;
+
+/// color of the tag of the outermost error
+const ERROR_COLOR: usize = 160;
+/// color of the tags of the sources of an error
+const CAUSE_COLOR: usize = 208;
+/// color of the captured backtrace
+#[cfg(feature = "backtrace")]
+const BACKTRACE_COLOR: usize = 245;
+
+/// borrows errors, including unsized ones (i.e.: `Box<dyn Error>` via
+/// auto-deref), as `&dyn Error`, see [dbg_err](crate::dbg_err!)
+pub trait AsDynError {
+    fn as_dyn_error(&self) -> &dyn Error;
+}
+
+impl<E: Error> AsDynError for E {
+    fn as_dyn_error(&self) -> &dyn Error {
+        self
+    }
+}
+
+impl AsDynError for dyn Error {
+    fn as_dyn_error(&self) -> &dyn Error {
+        self
+    }
+}
+
+impl AsDynError for dyn Error + Send + Sync {
+    fn as_dyn_error(&self) -> &dyn Error {
+        self
+    }
+}
+
+/// borrows the errors of results as `&dyn Error`, including boxed ones (i.e.:
+/// of `Result<T, Box<dyn Error>>`), see [ResultExt::dbg_err]
+///
+/// > the `Kind` ([Concrete] or [Boxed]) is inferred and only keeps apart the
+/// > implementations for errors and for boxed errors
+pub trait ErrorRef<Kind> {
+    fn error_ref(&self) -> &dyn Error;
+}
+
+/// the `Kind` of [ErrorRef] for implementors of [Error]
+pub enum Concrete {}
+
+/// the `Kind` of [ErrorRef] for `Box<dyn Error>` and `Box<dyn Error + Send + Sync>`
+pub enum Boxed {}
+
+impl<E: Error> ErrorRef<Concrete> for E {
+    fn error_ref(&self) -> &dyn Error {
+        self
+    }
+}
+
+impl ErrorRef<Boxed> for Box<dyn Error> {
+    fn error_ref(&self) -> &dyn Error {
+        self.as_ref()
+    }
+}
+
+impl ErrorRef<Boxed> for Box<dyn Error + Send + Sync> {
+    fn error_ref(&self) -> &dyn Error {
+        self.as_ref()
+    }
+}
+
+/// the given error followed by each of its [sources](Error::source)
+pub fn error_chain(error: &dyn Error) -> Vec<&dyn Error> {
+    let mut chain = vec![error];
+    let mut source = error.source();
+    while let Some(error) = source {
+        chain.push(error);
+        source = error.source();
+    }
+    chain
+}
+
+/// the first backtrace captured by the given error or its sources
+///
+/// > requires the `backtrace` feature and thereby the nightly
+/// > `error_generic_member_access` feature
+#[cfg(feature = "backtrace")]
+pub fn error_backtrace(error: &dyn Error) -> Option<&Backtrace> {
+    error_chain(error)
+        .into_iter()
+        .filter_map(|error| std::error::request_ref::<Backtrace>(error))
+        .find(|backtrace| backtrace.status() == BacktraceStatus::Captured)
+}
+
+fn wrap(tag: &str, color: usize, body: String) -> String {
+    [
+        crate::tag!(@open, tag, color),
+        indent_lines(body, &" ".repeat(INDENTATION)),
+        crate::tag!(@close, tag, color),
+    ]
+    .join("\n")
+}
+
+/// colorfully formats the given error with each of its sources nested in a
+/// `<caused by>` tag within the previous one, followed by the captured
+/// backtrace (if any) when the `backtrace` feature is enabled
+pub fn format_error(error: &dyn Error) -> String {
+    let chain = error_chain(error);
+    let mut nested: Option<String> = None;
+    for (index, error) in chain.iter().enumerate().rev() {
+        let mut lines = vec![auto(error.to_string())];
+        lines.extend(nested.take());
+        nested = Some(if index == 0 {
+            #[cfg(feature = "backtrace")]
+            if let Some(backtrace) = error_backtrace(*error) {
+                lines.push(wrap(
+                    "backtrace",
+                    BACKTRACE_COLOR,
+                    fore(backtrace.to_string().trim_end(), BACKTRACE_COLOR),
+                ));
+            }
+            wrap("error", ERROR_COLOR, lines.join("\n"))
+        } else {
+            wrap("caused by", CAUSE_COLOR, lines.join("\n"))
+        });
+    }
+    nested.unwrap_or_default()
+}
+
+/// extension of [Result] which prints either variant in passing, see
+/// [Tap](crate::tap::Tap) for other values
+pub trait ResultExt<T, E> {
+    /// prints the value (if any) via [format_dbg](crate::format_dbg!) along
+    /// with the caller location and returns the result unchanged
+    #[track_caller]
+    fn dbg_ok(self) -> Self
+    where
+        T: Debug;
+
+    /// prints the error (if any) via [format_error] along with the caller
+    /// location and returns the result unchanged
+    #[track_caller]
+    fn dbg_err<Kind>(self) -> Self
+    where
+        E: ErrorRef<Kind>;
+}
+
+impl<T, E> ResultExt<T, E> for Result<T, E> {
+    #[track_caller]
+    fn dbg_ok(self) -> Self
+    where
+        T: Debug,
+    {
+        if let Ok(value) = &self {
+            emit_tap(
+                "ok",
+                Location::caller(),
+                crate::format_dbg!(@labeled "Ok", value, limits()),
+            );
+        }
+        self
+    }
+
+    #[track_caller]
+    fn dbg_err<Kind>(self) -> Self
+    where
+        E: ErrorRef<Kind>,
+    {
+        if let Err(error) = &self {
+            emit_tap(
+                "error",
+                Location::caller(),
+                indent_lines(format_error(error.error_ref()), &" ".repeat(INDENTATION)),
+            );
+        }
+        self
+    }
+}
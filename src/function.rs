@@ -0,0 +1,225 @@
+//! structured names of the functions calling [function](crate::function!) and friends
+use crate::color::{auto, fore};
+use crate::meta::short_type_name;
+use std::fmt::{self, Display};
+
+/// color of the punctuation of qualified names (i.e.: `<`, ` as `, `::`)
+const PUNCTUATION_COLOR: usize = 7;
+
+/// compiler-generated segment of a function path which nests a body
+///
+/// > rustc names closures, `async` blocks and the bodies of `async fn`s
+/// > alike (`{{closure}}`), so async code is marked as a closure
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Marker {
+    /// `{{closure}}`, which includes `async` blocks and the bodies of `async fn`s
+    Closure,
+}
+
+impl Marker {
+    /// the marker of the given path segment, if a closure, other
+    /// compiler-generated segments (i.e.: `{{constant}}`) are not markers
+    pub fn parse(segment: &str) -> Option<Marker> {
+        (segment == "{{closure}}").then_some(Marker::Closure)
+    }
+
+    /// short glyph of the marker
+    pub fn glyph(&self) -> &'static str {
+        match self {
+            Marker::Closure => "λ",
+        }
+    }
+
+    /// ANSI-256 color of the glyph of the marker
+    pub fn color(&self) -> usize {
+        match self {
+            Marker::Closure => 141,
+        }
+    }
+}
+
+/// whether the given path segment is generated by the compiler (i.e.:
+/// `{{closure}}`, `{{constant}}` or `{{constructor}}`)
+fn is_generated(segment: &str) -> bool {
+    segment.starts_with("{{") && segment.ends_with("}}")
+}
+
+/// name of a function split into its module, impl and trait context, i.e.:
+/// `<my::Bar<_> as my::Foo>::go::{{closure}}` => `<Bar as Foo>::go λ`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FunctionName {
+    /// [module_path] of the call
+    pub module: String,
+    /// self type of the enclosing impl (if any)
+    pub impl_type: Option<String>,
+    /// trait of the enclosing impl (if any)
+    pub trait_name: Option<String>,
+    /// function (or nested functions, i.e.: `outer::inner`)
+    pub function: String,
+    /// closures (including async blocks) the call is nested within
+    pub markers: Vec<Marker>,
+}
+
+/// splits the given path at `::` outside of angle brackets
+fn split_path(path: &str) -> Vec<&str> {
+    let mut segments = Vec::new();
+    let mut nesting = 0usize;
+    let mut start = 0;
+    let bytes = path.as_bytes();
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'<' => nesting += 1,
+            b'>' => nesting = nesting.saturating_sub(1),
+            b':' if nesting == 0 && bytes.get(index + 1) == Some(&b':') => {
+                segments.push(&path[start..index]);
+                index += 2;
+                start = index;
+                continue;
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+    segments.push(&path[start..]);
+    segments
+}
+
+/// removes the generic arguments which are only placeholders, i.e.: `Bar<_>` => `Bar`
+fn strip_placeholders(name: &str) -> String {
+    let mut name = name.to_string();
+    let mut from = 0;
+    while let Some(open) = name[from..].find('<').map(|index| from + index) {
+        match name[open..].find('>').map(|index| open + index) {
+            Some(close)
+                if name[open + 1..close]
+                    .chars()
+                    .all(|c| matches!(c, '_' | ',' | ' ')) =>
+            {
+                name.replace_range(open..=close, "");
+            }
+            _ => from = open + 1,
+        }
+    }
+    name
+}
+
+fn clean_type(name: &str) -> String {
+    strip_placeholders(&short_type_name(name.trim()))
+}
+
+/// whether the given path segment names a type (rather than a function)
+fn is_type(segment: &str) -> bool {
+    segment.contains('<') || segment.starts_with(|c: char| c.is_uppercase())
+}
+
+impl FunctionName {
+    /// parses the [type_name](std::any::type_name) of a function item
+    /// nested within the function being named (whose last segment is
+    /// stripped) relative to the given [module_path]
+    pub fn parse(type_name: &str, module: &str) -> FunctionName {
+        let path = type_name.strip_suffix("::f").unwrap_or(type_name);
+        let mut markers = Vec::new();
+        let segments = split_path(path)
+            .into_iter()
+            .filter(|segment| {
+                markers.extend(Marker::parse(segment));
+                !is_generated(segment)
+            })
+            .collect::<Vec<&str>>();
+        let mut impl_type = None;
+        let mut trait_name = None;
+        let qualified = segments
+            .first()
+            .and_then(|segment| segment.strip_prefix('<')?.strip_suffix('>'));
+        let function = if let Some(qualified) = qualified {
+            if let Some((self_type, name)) = qualified.split_once(" as ") {
+                impl_type = Some(clean_type(self_type));
+                trait_name = Some(clean_type(name));
+            } else if let Some((name, self_type)) = qualified
+                .strip_prefix("impl ")
+                .and_then(|qualified| qualified.split_once(" for "))
+            {
+                impl_type = Some(clean_type(self_type));
+                trait_name = Some(clean_type(name));
+            } else {
+                impl_type = Some(clean_type(qualified));
+            }
+            segments[1..].join("::")
+        } else {
+            let module_segments = split_path(module);
+            let rest = segments
+                .strip_prefix(module_segments.as_slice())
+                .unwrap_or(&segments);
+            let last = rest.len().saturating_sub(1);
+            match rest[..last].iter().rposition(|segment| is_type(segment)) {
+                Some(index) => {
+                    impl_type = Some(clean_type(rest[index]));
+                    rest[index + 1..].join("::")
+                }
+                None => rest.join("::"),
+            }
+        };
+        FunctionName {
+            module: module.to_string(),
+            impl_type,
+            trait_name,
+            function,
+            markers,
+        }
+    }
+
+    /// number of closures (including async blocks) the call is nested within
+    pub fn closure_depth(&self) -> usize {
+        self.markers.len()
+    }
+
+    /// the glyphs of the [markers](FunctionName::markers)
+    pub fn glyphs(&self) -> String {
+        self.markers.iter().map(Marker::glyph).collect()
+    }
+
+    /// colorfully formats the name, each part colored via [auto] and each
+    /// marker as its colored [glyph](Marker::glyph)
+    pub fn format(&self) -> String {
+        let punctuation = |text: &str| fore(text, PUNCTUATION_COLOR);
+        let mut result = String::new();
+        match (&self.impl_type, &self.trait_name) {
+            (Some(impl_type), Some(trait_name)) => {
+                result.push_str(&punctuation("<"));
+                result.push_str(&auto(impl_type));
+                result.push_str(&punctuation(" as "));
+                result.push_str(&auto(trait_name));
+                result.push_str(&punctuation(">::"));
+            }
+            (Some(impl_type), None) => {
+                result.push_str(&auto(impl_type));
+                result.push_str(&punctuation("::"));
+            }
+            _ => {}
+        }
+        result.push_str(&auto(&self.function));
+        if !self.markers.is_empty() {
+            result.push(' ');
+            for marker in &self.markers {
+                result.push_str(&fore(marker.glyph(), marker.color()));
+            }
+        }
+        result
+    }
+}
+
+impl Display for FunctionName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.impl_type, &self.trait_name) {
+            (Some(impl_type), Some(trait_name)) => write!(f, "<{impl_type} as {trait_name}>::")?,
+            (Some(impl_type), None) => write!(f, "{impl_type}::")?,
+            _ => {}
+        }
+        write!(f, "{}", self.function)?;
+        if !self.markers.is_empty() {
+            write!(f, " {}", self.glyphs())?;
+        }
+        Ok(())
+    }
+}
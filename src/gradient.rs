@@ -0,0 +1,158 @@
+//! gradient and rainbow rendering of text interpolated in OKLab
+use crate::color::{bg, bg_rgb, fg, fg_rgb, get_ansi_rgb, reset, rgb_to_byte, truecolor};
+use crate::space::{oklab_to_rgb, oklch_to_oklab, rgb_to_oklab};
+use std::fmt::Display;
+
+/// lightness of the colors of [rainbow] in OKLCh
+const RAINBOW_LIGHTNESS: f64 = 0.75;
+/// chroma of the colors of [rainbow] in OKLCh
+const RAINBOW_CHROMA: f64 = 0.14;
+
+/// splits the given text in (approximate) grapheme clusters: a base
+/// character followed by combining marks, variation selectors, emoji
+/// modifiers or zero-width-joined characters
+pub fn graphemes(text: &str) -> Vec<&str> {
+    let mut graphemes = Vec::<&str>::new();
+    let mut start = 0;
+    let mut previous: Option<char> = None;
+    let mut regional_indicators = 0;
+    for (index, c) in text.char_indices() {
+        let extends = previous.is_some_and(|previous| {
+            previous == '\u{200d}'
+                || is_extending(c)
+                || (is_regional_indicator(c) && regional_indicators % 2 == 1)
+        });
+        if index > 0 && !extends {
+            graphemes.push(&text[start..index]);
+            start = index;
+        }
+        regional_indicators = if is_regional_indicator(c) {
+            regional_indicators + 1
+        } else {
+            0
+        };
+        previous = Some(c);
+    }
+    if start < text.len() {
+        graphemes.push(&text[start..]);
+    }
+    graphemes
+}
+
+fn is_extending(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x0300..=0x036f
+            | 0x1ab0..=0x1aff
+            | 0x1dc0..=0x1dff
+            | 0x200c..=0x200d
+            | 0x20d0..=0x20ff
+            | 0xfe00..=0xfe0f
+            | 0xfe20..=0xfe2f
+            | 0x1f3fb..=0x1f3ff
+            | 0xe0020..=0xe007f
+    )
+}
+
+fn is_regional_indicator(c: char) -> bool {
+    matches!(c as u32, 0x1f1e6..=0x1f1ff)
+}
+
+/// interpolates between two `[red, green, blue]` triples in OKLab,
+/// `position` goes from `0.0` (`from`) to `1.0` (`to`)
+pub fn interpolate_rgb(from: [u8; 3], to: [u8; 3], position: f64) -> [u8; 3] {
+    let position = position.clamp(0.0, 1.0);
+    let from = rgb_to_oklab(from);
+    let to = rgb_to_oklab(to);
+    oklab_to_rgb([
+        from[0] + (to[0] - from[0]) * position,
+        from[1] + (to[1] - from[1]) * position,
+        from[2] + (to[2] - from[2]) * position,
+    ])
+}
+
+/// returns `count` `[red, green, blue]` triples going from the given ANSI-256 color to the other
+pub fn gradient_colors(count: usize, from: usize, to: usize) -> Vec<[u8; 3]> {
+    let from = get_ansi_rgb(from);
+    let to = get_ansi_rgb(to);
+    (0..count)
+        .map(|index| interpolate_rgb(from, to, position(index, count)))
+        .collect()
+}
+
+/// returns `count` `[red, green, blue]` triples going around the hue circle in OKLCh
+pub fn rainbow_colors(count: usize) -> Vec<[u8; 3]> {
+    (0..count)
+        .map(|index| {
+            oklab_to_rgb(oklch_to_oklab([
+                RAINBOW_LIGHTNESS,
+                RAINBOW_CHROMA,
+                30.0 + 360.0 * index as f64 / count.max(1) as f64,
+            ]))
+        })
+        .collect()
+}
+
+fn position(index: usize, count: usize) -> f64 {
+    if count > 1 {
+        index as f64 / (count - 1) as f64
+    } else {
+        0.0
+    }
+}
+
+fn paint(text: &str, colors: Vec<[u8; 3]>, background: bool) -> String {
+    let truecolor = truecolor();
+    reset(
+        graphemes(text)
+            .into_iter()
+            .zip(colors)
+            .map(|(grapheme, rgb)| match (background, truecolor) {
+                (false, true) => fg_rgb(grapheme, rgb),
+                (false, false) => fg(grapheme, rgb_to_byte(rgb) as usize),
+                (true, true) => bg_rgb(grapheme, rgb),
+                (true, false) => bg(grapheme, rgb_to_byte(rgb) as usize),
+            })
+            .collect::<String>(),
+    )
+}
+
+/// colorize the foreground of each grapheme of the given text along a
+/// gradient between two ANSI-256 colors and reset afterwards
+///
+/// > quantized via [rgb_to_byte] unless [truecolor] is supported
+pub fn gradient<T: Display>(text: T, from: usize, to: usize) -> String {
+    let text = text.to_string();
+    let count = graphemes(&text).len();
+    paint(&text, gradient_colors(count, from, to), false)
+}
+
+/// colorize the background of each grapheme of the given text along a
+/// gradient between two ANSI-256 colors and reset afterwards
+pub fn gradient_bg<T: Display>(text: T, from: usize, to: usize) -> String {
+    let text = text.to_string();
+    let count = graphemes(&text).len();
+    paint(&text, gradient_colors(count, from, to), true)
+}
+
+/// colorize the foreground of each grapheme of the given text along the hue circle
+pub fn rainbow<T: Display>(text: T) -> String {
+    let text = text.to_string();
+    let count = graphemes(&text).len();
+    paint(&text, rainbow_colors(count), false)
+}
+
+/// maps a value between `min` and `max` onto a green => yellow => red ANSI-256 color
+pub fn heat_color(value: f64, min: f64, max: f64) -> u8 {
+    let position = if max > min {
+        ((value - min) / (max - min)).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let [green, yellow, red] = [[95, 215, 95], [255, 215, 95], [255, 95, 95]];
+    rgb_to_byte(if position < 0.5 {
+        interpolate_rgb(green, yellow, position * 2.0)
+    } else {
+        interpolate_rgb(yellow, red, (position - 0.5) * 2.0)
+    })
+}
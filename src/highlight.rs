@@ -0,0 +1,247 @@
+//! syntax highlighting of the output of `{:#?}`
+use crate::color::{fore, rgb_to_byte};
+use crate::gradient::rainbow_colors;
+use crate::theme::theme;
+use std::fmt::Debug;
+
+/// number of colors [bracket_color] cycles through
+const BRACKET_COLORS: usize = 6;
+/// identifiers highlighted with [Theme::keyword](crate::theme::Theme::keyword)
+const KEYWORDS: [&str; 6] = ["None", "Some", "Ok", "Err", "true", "false"];
+
+/// category of a token of the output of `{:#?}`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenKind {
+    /// names of structs, enums and variants (i.e.: `Person`, `Token::Word`)
+    TypeName,
+    /// names of fields, followed by `:`
+    Field,
+    /// string literals (i.e.: `"text"`)
+    String,
+    /// char literals (i.e.: `'c'`)
+    Char,
+    /// lifetimes and labels (i.e.: `'a`, `'static`)
+    Lifetime,
+    /// integer and float literals (i.e.: `-1`, `0xff`, `1.5e-7`)
+    Number,
+    /// `None`, `Some`, `Ok`, `Err`, `true` and `false`
+    Keyword,
+    /// other identifiers
+    Identifier,
+    /// `(`, `[` or `{` at the given nesting depth
+    Open(usize),
+    /// `)`, `]` or `}` at the given nesting depth
+    Close(usize),
+    /// colons, commas and any other character
+    Punctuation,
+    /// spaces and newlines
+    Whitespace,
+}
+
+/// a token of the output of `{:#?}`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Token<'a> {
+    /// category of the token
+    pub kind: TokenKind,
+    /// text of the token
+    pub text: &'a str,
+}
+
+/// ANSI-256 color of brackets at the given nesting depth
+pub fn bracket_color(depth: usize) -> u8 {
+    rgb_to_byte(rainbow_colors(BRACKET_COLORS)[depth % BRACKET_COLORS])
+}
+
+/// splits the output of `{:#?}` (or `{:?}`) into [Token]s
+pub fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let bytes = text.as_bytes();
+    let mut tokens = Vec::<Token>::new();
+    let mut depth = 0usize;
+    let mut index = 0;
+    while index < bytes.len() {
+        let start = index;
+        let c = text[index..].chars().next().unwrap_or_default();
+        let kind = match c {
+            c if c.is_whitespace() => {
+                index = scan(text, index, |c| c.is_whitespace());
+                TokenKind::Whitespace
+            }
+            '"' => {
+                index = scan_quoted(bytes, index, b'"');
+                TokenKind::String
+            }
+            '\'' => match char_literal(text, index) {
+                Some(end) => {
+                    index = end;
+                    TokenKind::Char
+                }
+                None if text[index + 1..]
+                    .chars()
+                    .next()
+                    .is_some_and(|c| c.is_alphabetic() || c == '_') =>
+                {
+                    index = scan(text, index + 1, |c| c.is_alphanumeric() || c == '_');
+                    TokenKind::Lifetime
+                }
+                None => {
+                    index += 1;
+                    TokenKind::Punctuation
+                }
+            },
+            '-' if bytes.get(index + 1).is_some_and(u8::is_ascii_digit) => {
+                index = scan_number(bytes, index + 1);
+                TokenKind::Number
+            }
+            c if c.is_ascii_digit() => {
+                index = scan_number(bytes, index);
+                TokenKind::Number
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                index = scan(text, index, |c| c.is_alphanumeric() || c == '_');
+                while text[index..].starts_with("::")
+                    && text[index + 2..]
+                        .chars()
+                        .next()
+                        .is_some_and(|c| c.is_alphabetic() || c == '_')
+                {
+                    index = scan(text, index + 2, |c| c.is_alphanumeric() || c == '_');
+                }
+                identifier(&text[start..index], &text[index..])
+            }
+            '(' | '[' | '{' => {
+                index += 1;
+                depth += 1;
+                TokenKind::Open(depth - 1)
+            }
+            ')' | ']' | '}' => {
+                index += 1;
+                depth = depth.saturating_sub(1);
+                TokenKind::Close(depth)
+            }
+            c => {
+                index += c.len_utf8();
+                TokenKind::Punctuation
+            }
+        };
+        tokens.push(Token {
+            kind,
+            text: &text[start..index],
+        });
+    }
+    tokens
+}
+
+fn scan(text: &str, start: usize, predicate: impl Fn(char) -> bool) -> usize {
+    text[start..]
+        .char_indices()
+        .find(|(_, c)| !predicate(*c))
+        .map(|(offset, _)| start + offset)
+        .unwrap_or(text.len())
+}
+
+/// end of the quoted literal starting at `start`, honoring backslash escapes
+fn scan_quoted(bytes: &[u8], start: usize, quote: u8) -> usize {
+    let mut index = start + 1;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' => index += 2,
+            byte if byte == quote => return index + 1,
+            _ => index += 1,
+        }
+    }
+    bytes.len()
+}
+
+/// end of the char literal starting at `start`, a single char or escape
+/// between quotes, or [None] if it is not one (i.e.: a lifetime)
+fn char_literal(text: &str, start: usize) -> Option<usize> {
+    let rest = &text[start + 1..];
+    let length = match rest.chars().next()? {
+        '\'' => return None,
+        '\\' => match rest[1..].chars().next()? {
+            'n' | 'r' | 't' | '0' | '\\' | '\'' | '"' => 2,
+            'x' if rest
+                .get(2..4)
+                .is_some_and(|hex| hex.bytes().all(|byte| byte.is_ascii_hexdigit())) =>
+            {
+                4
+            }
+            'u' => {
+                let close = rest.find('}')?;
+                let hex = rest.get(3..close)?;
+                (rest[2..].starts_with('{')
+                    && (1..=6).contains(&hex.len())
+                    && hex.bytes().all(|byte| byte.is_ascii_hexdigit()))
+                .then_some(close + 1)?
+            }
+            _ => return None,
+        },
+        c => c.len_utf8(),
+    };
+    rest[length..]
+        .starts_with('\'')
+        .then_some(start + 1 + length + 1)
+}
+
+fn scan_number(bytes: &[u8], start: usize) -> usize {
+    let mut index = start;
+    while index < bytes.len() {
+        match bytes[index] {
+            b'0'..=b'9' | b'a'..=b'z' | b'A'..=b'Z' | b'_' => index += 1,
+            b'.' if bytes.get(index + 1).is_some_and(u8::is_ascii_digit) => index += 1,
+            b'-' | b'+' if matches!(bytes[index - 1], b'e' | b'E') => index += 1,
+            _ => break,
+        }
+    }
+    index
+}
+
+fn identifier(text: &str, rest: &str) -> TokenKind {
+    if KEYWORDS.contains(&text) {
+        TokenKind::Keyword
+    } else if rest.starts_with(':') && !rest.starts_with("::") {
+        TokenKind::Field
+    } else if text
+        .rsplit("::")
+        .next()
+        .and_then(|last| last.chars().next())
+        .is_some_and(char::is_uppercase)
+    {
+        TokenKind::TypeName
+    } else {
+        TokenKind::Identifier
+    }
+}
+
+/// colorizes the output of `{:#?}` (or `{:?}`) token by token according to the current [theme]
+pub fn highlight(text: &str) -> String {
+    let theme = theme();
+    tokenize(text)
+        .into_iter()
+        .map(|token| {
+            let color = match token.kind {
+                TokenKind::Whitespace => return token.text.to_string(),
+                TokenKind::TypeName => theme.type_name,
+                TokenKind::Field => theme.field,
+                TokenKind::String => theme.string,
+                TokenKind::Char => theme.char,
+                TokenKind::Lifetime => theme.lifetime,
+                TokenKind::Number => theme.number,
+                TokenKind::Keyword => theme.keyword,
+                TokenKind::Identifier => theme.identifier,
+                TokenKind::Open(depth) | TokenKind::Close(depth) if theme.rainbow_brackets => {
+                    bracket_color(depth)
+                }
+                TokenKind::Open(_) | TokenKind::Close(_) | TokenKind::Punctuation => {
+                    theme.punctuation
+                }
+            };
+            fore(token.text, color.into())
+        })
+        .collect()
+}
+
+/// [highlight]s the `{:#?}` rendering of the given value
+pub fn highlight_debug<T: Debug + ?Sized>(value: &T) -> String {
+    highlight(&format!("{value:#?}"))
+}
@@ -0,0 +1,124 @@
+//! OSC 8 terminal hyperlinks which open the source of a diagnostic when clicked
+use crate::location::SourceLocation;
+use crate::snippet::{resolve_source, source_root};
+use std::io::IsTerminal;
+use std::path::Path;
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+const HYPERLINKS_DEFAULT: u8 = 0;
+const HYPERLINKS_ON: u8 = 1;
+const HYPERLINKS_OFF: u8 = 2;
+
+static HYPERLINKS: AtomicU8 = AtomicU8::new(HYPERLINKS_DEFAULT);
+static EDITOR_URL: RwLock<Option<String>> = RwLock::new(None);
+
+/// forces hyperlinks on or off, [None] restores the default which detects
+/// whether stderr is a terminal that supports them, see [hyperlinks_supported]
+pub fn set_hyperlinks(enabled: Option<bool>) {
+    HYPERLINKS.store(
+        match enabled {
+            None => HYPERLINKS_DEFAULT,
+            Some(true) => HYPERLINKS_ON,
+            Some(false) => HYPERLINKS_OFF,
+        },
+        Ordering::Relaxed,
+    );
+}
+
+/// whether locations are wrapped in hyperlinks, see [set_hyperlinks]
+pub fn hyperlinks() -> bool {
+    match HYPERLINKS.load(Ordering::Relaxed) {
+        HYPERLINKS_ON => true,
+        HYPERLINKS_OFF => false,
+        _ => hyperlinks_supported(),
+    }
+}
+
+/// returns true if stderr is a terminal known to support OSC 8 hyperlinks
+/// according to the environment variables it sets
+pub fn hyperlinks_supported() -> bool {
+    if !std::io::stderr().is_terminal() {
+        return false;
+    }
+    let var = |name: &str| std::env::var(name).unwrap_or_default();
+    matches!(
+        var("TERM_PROGRAM").as_str(),
+        "iTerm.app" | "WezTerm" | "vscode" | "Hyper" | "ghostty" | "rio"
+    ) || ["kitty", "alacritty", "foot", "ghostty", "wezterm"]
+        .iter()
+        .any(|term| var("TERM").contains(term))
+        || var("VTE_VERSION")
+            .parse::<u32>()
+            .is_ok_and(|version| version >= 5000)
+        || ["WT_SESSION", "KONSOLE_VERSION", "KITTY_WINDOW_ID"]
+            .iter()
+            .any(|name| std::env::var_os(name).is_some())
+}
+
+/// links locations to the given URL template rather than `file://`, i.e.:
+/// `vscode://file/{path}:{line}:{col}`
+pub fn set_editor_url(template: Option<String>) {
+    *EDITOR_URL
+        .write()
+        .unwrap_or_else(|error| error.into_inner()) = template;
+}
+
+/// the template set via [set_editor_url]
+pub fn editor_url() -> Option<String> {
+    EDITOR_URL
+        .read()
+        .unwrap_or_else(|error| error.into_inner())
+        .clone()
+}
+
+/// wraps the given text in an OSC 8 hyperlink to the given URL
+pub fn hyperlink<T: std::fmt::Display>(text: T, url: &str) -> String {
+    format!("\x1b]8;;{url}\x1b\\{text}\x1b]8;;\x1b\\")
+}
+
+/// percent-encodes the given path for use in a URL, keeping `/` and the
+/// characters which never need escaping
+fn encode_path(path: &str) -> String {
+    path.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' | b'/' => {
+                char::from(byte).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
+}
+
+/// URL of the file of the given location via the [editor_url] template or
+/// else `file://`, relative paths (i.e.: `file!()`) resolved against the
+/// `CARGO_MANIFEST_DIR` of the calling crate at compile time (and its
+/// ancestors, see [resolve_source]) or else the [source_root], if any
+pub fn file_url(location: &SourceLocation) -> String {
+    let path = Path::new(location.file);
+    let path = match location.manifest_dir {
+        _ if path.is_absolute() => path.to_path_buf(),
+        Some(manifest_dir) => resolve_source(manifest_dir, location.file)
+            .unwrap_or_else(|| Path::new(manifest_dir).join(path)),
+        None => source_root()
+            .map(|root| root.join(path))
+            .unwrap_or_else(|| path.to_path_buf()),
+    };
+    let path = encode_path(&path.to_string_lossy());
+    match editor_url() {
+        Some(template) => template
+            .replace("{path}", &path)
+            .replace("{line}", &location.line.to_string())
+            .replace("{col}", &location.column.to_string()),
+        None => format!("file://{path}"),
+    }
+}
+
+/// wraps the given text in a hyperlink to the given location if [hyperlinks] are enabled
+pub fn link_location<T: std::fmt::Display>(text: T, location: &SourceLocation) -> String {
+    if hyperlinks() {
+        hyperlink(text, &file_url(location))
+    } else {
+        text.to_string()
+    }
+}
@@ -0,0 +1,119 @@
+//! thread, process and async task identity prefixed to each line of diagnostics
+use crate::color::{auto, fore};
+use std::cell::Cell;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::RwLock;
+use std::task::{Context, Poll};
+
+static PREFIXES: RwLock<Prefixes> = RwLock::new(Prefixes::NONE);
+
+thread_local! {
+    static TASK: Cell<Option<u64>> = const { Cell::new(None) };
+}
+
+/// identity fields prefixed to each line printed via [emit](crate::output::emit)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Prefixes {
+    /// name (or id) of the current thread
+    pub thread: bool,
+    /// id of the current process
+    pub process: bool,
+    /// id of the current async task (if any), see [in_task]
+    pub task: bool,
+}
+
+impl Prefixes {
+    /// no prefixes
+    pub const NONE: Prefixes = Prefixes {
+        thread: false,
+        process: false,
+        task: false,
+    };
+    /// all prefixes
+    pub const ALL: Prefixes = Prefixes {
+        thread: true,
+        process: true,
+        task: true,
+    };
+}
+
+/// sets the identity fields prefixed to each line of diagnostics
+pub fn set_prefixes(prefixes: Prefixes) {
+    *PREFIXES.write().unwrap_or_else(|error| error.into_inner()) = prefixes;
+}
+
+/// the prefixes set via [set_prefixes] or [Prefixes::NONE]
+pub fn prefixes() -> Prefixes {
+    *PREFIXES.read().unwrap_or_else(|error| error.into_inner())
+}
+
+/// name of the current thread or else "thread-{id}"
+pub fn thread_name() -> String {
+    let thread = std::thread::current();
+    match thread.name() {
+        Some(name) => name.to_string(),
+        None => {
+            let id = format!("{:?}", thread.id());
+            format!(
+                "thread-{}",
+                id.chars().filter(char::is_ascii_digit).collect::<String>()
+            )
+        }
+    }
+}
+
+/// id of the async task being polled on the current thread, see [in_task]
+pub fn task_id() -> Option<u64> {
+    TASK.with(Cell::get)
+}
+
+/// future returned by [in_task]
+pub struct InTask<F> {
+    id: u64,
+    future: Pin<Box<F>>,
+}
+
+impl<F: Future> Future for InTask<F> {
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<F::Output> {
+        let previous = TASK.with(|task| task.replace(Some(self.id)));
+        let poll = self.future.as_mut().poll(cx);
+        TASK.with(|task| task.set(previous));
+        poll
+    }
+}
+
+/// tags the given future with the given task id (i.e.: `tokio::task::id()`)
+/// which, while it is being polled, is returned by [task_id] and prefixed to
+/// diagnostics if enabled via [set_prefixes]
+pub fn in_task<F: Future>(id: u64, future: F) -> InTask<F> {
+    InTask {
+        id,
+        future: Box::pin(future),
+    }
+}
+
+/// the enabled [prefixes] of the current thread, process and task, each
+/// colored via [auto], followed by a space (or empty if none is enabled)
+pub fn format_prefix() -> String {
+    let prefixes = prefixes();
+    let mut fields = Vec::<String>::new();
+    if prefixes.process {
+        fields.push(format!("{}{}", fore("pid:", 7), auto(std::process::id())));
+    }
+    if prefixes.thread {
+        fields.push(auto(thread_name()));
+    }
+    if prefixes.task
+        && let Some(id) = task_id()
+    {
+        fields.push(format!("{}{}", fore("task:", 7), auto(id)));
+    }
+    if fields.is_empty() {
+        String::new()
+    } else {
+        format!("{} ", fields.join(" "))
+    }
+}
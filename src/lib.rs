@@ -1,18 +1,121 @@
 #![allow(unused)]
 #![feature(intra_doc_pointers)]
+#![cfg_attr(feature = "backtrace", feature(error_generic_member_access))]
 #![doc(issue_tracker_base_url = "https://github.com/gabrielfalcao/debug-et-diagnostics/issues/")]
 //! set of macros and tools to colorfully debug and diagnose non-trivial code
 
 pub mod color;
+pub mod color_debug;
+pub mod cvd;
+pub mod diag;
+pub mod error;
+pub mod function;
+pub mod gradient;
+pub mod highlight;
+pub mod hyperlink;
+pub mod identity;
+pub mod limits;
+pub mod location;
+pub mod meta;
+pub mod output;
+pub mod parse;
+pub mod report;
+pub mod scope;
+pub mod snippet;
+pub mod space;
+pub mod tap;
+pub mod theme;
+pub mod timestamp;
+pub mod timing;
 
 #[doc(inline)]
 pub use color::{
-    ansi, ansi_clear, auto, auto_bright, auto_dark, back, bg, bgfg, bright, bright_rgb_band, byte,
-    byte_bin, byte_hex, couple, cube_ansi_256, dark, dark_rgb_band, fg, fore, format_slice_debug,
-    format_slice_display, format_slice_hex, from_byte, from_bytes, from_debug, from_display,
-    get_ansi_rgb, invert_ansi, invert_bw, invert_rgb, is_bright_rgb_band, is_dark_rgb_band,
-    merge_rgb, non_zero_be_bytes, pad, pad_columns, reset, rgb_from_byte, rgb_from_bytes,
-    rgb_from_display, rgb_to_byte, term_cols, wrap, STD_COLORS,
+    ansi, ansi_clear, auto, auto_bright, auto_dark, back, bg, bg_rgb, bgfg, bright, bright_rgb_band,
+    byte, byte_bin, byte_hex, color_enabled, couple, cube_ansi_256, dark, dark_rgb_band, fg, fg_rgb,
+    fore, format_slice_debug, format_slice_display, format_slice_hex, from_byte, from_bytes,
+    from_debug, from_display, get_ansi_rgb, invert_ansi, invert_bw,
+    invert_rgb, is_bright_rgb_band, is_dark_rgb_band, lighten, merge_rgb, non_zero_be_bytes, pad,
+    pad_columns, reset, rgb_from_byte, rgb_from_bytes, rgb_from_display, rgb_to_byte, set_color,
+    strip_ansi, term_cols, truecolor, wrap, STD_COLORS,
 };
+#[doc(inline)]
+pub use color_debug::{
+    debug_bytes, debug_hex, debug_value, redacted, ColorDebug, DebugStruct, DebugTuple,
+};
+#[doc(inline)]
+pub use cvd::{
+    cvd_safe, cvd_safe_color, cvd_safe_palette, deuteranopia, nearest_cvd_safe, protanopia,
+    rgb_distance, set_cvd_safe, simulate, simulate_rgb, tritanopia, ColorAllocator, Deficiency,
+};
+#[doc(inline)]
+pub use error::{error_chain, format_error, AsDynError, ErrorRef, ResultExt};
+#[cfg(feature = "backtrace")]
+#[doc(inline)]
+pub use error::error_backtrace;
+#[doc(inline)]
+pub use function::{FunctionName, Marker};
+#[doc(inline)]
+pub use gradient::{
+    gradient, gradient_bg, gradient_colors, graphemes, heat_color, interpolate_rgb, rainbow,
+    rainbow_colors,
+};
+#[doc(inline)]
+pub use highlight::{bracket_color, highlight, highlight_debug, tokenize, Token, TokenKind};
+#[doc(inline)]
+pub use hyperlink::{
+    editor_url, file_url, hyperlink, hyperlinks, hyperlinks_supported, link_location,
+    set_editor_url, set_hyperlinks,
+};
+#[doc(inline)]
+pub use identity::{
+    format_prefix, in_task, prefixes, set_prefixes, task_id, thread_name, InTask, Prefixes,
+};
+#[doc(inline)]
+pub use limits::{format_debug, limit_debug, limits, set_limits, Limits};
+#[doc(inline)]
+pub use location::{
+    display_path, format_begin, format_end, format_filename, format_location, location_style,
+    plain_location, registry_crate, set_location_style, LocationStyle, PathStyle, SourceLocation,
+};
+#[doc(inline)]
+pub use meta::{format_meta, format_type_name, short_type_name};
+#[doc(inline)]
+pub use output::{
+    branch, colored, columns, emit, emit_branch, guide_color, indent_lines, indentation,
+    set_tree_guides, tree_guides, INDENTATION,
+};
+#[doc(inline)]
+pub use parse::{color_name, parse_color, CSS_COLORS, Color, IntoAnsi};
+#[doc(inline)]
+pub use report::{Label, Report, Severity};
+#[doc(inline)]
+pub use scope::{depth, enter, exit, Scope};
+#[doc(inline)]
+pub use snippet::{
+    emit_snippet, format_snippet, render_snippet, resolve_source, set_source_root,
+    set_source_snippets, source_root, source_snippets, CONTEXT,
+};
+#[doc(inline)]
+pub use space::{
+    delta_e, hsl_to_rgb, hsv_to_rgb, lab_to_rgb, oklab_distance, oklab_to_oklch, oklab_to_rgb,
+    oklch_to_oklab, oklch_to_rgb, rgb_to_hsl, rgb_to_hsv, rgb_to_lab, rgb_to_oklab,
+};
+#[doc(inline)]
+pub use tap::{DbgEach, IteratorExt, OptionExt, Tap};
+#[doc(inline)]
+pub use theme::{set_theme, theme, Theme};
+#[doc(inline)]
+pub use timestamp::{
+    format_clock, format_timestamp, mark_start, parse_timestamps, rfc3339, set_timestamps,
+    since_last_diagnostic, since_start, timestamp_width, timestamps, Timestamps, TIMESTAMPS_ENV,
+};
+#[doc(inline)]
+pub use timing::{
+    duration_color, format_duration, format_step_header, humanize_duration, set_step_elapsed,
+    since_last_step, step_elapsed, Stopwatch,
+};
+#[cfg(feature = "macros")]
+#[doc(inline)]
+pub use debug_et_diagnostics_macros::{trace, ColorDebug};
 
 mod macros;
@@ -0,0 +1,264 @@
+//! depth and length limits of large `{:#?}` dumps
+use crate::highlight::{Token, TokenKind, highlight, tokenize};
+use crate::meta::short_type_name;
+use std::fmt::Debug;
+use std::sync::RwLock;
+
+static LIMITS: RwLock<Limits> = RwLock::new(Limits::NONE);
+
+/// limits applied by [format_dbg](crate::format_dbg) and friends, [None] means unlimited
+///
+/// > set per call via i.e.: `dbg!(depth=2, items=10, value)`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Limits {
+    /// maximum nesting depth, deeper regions are folded into i.e.: `[…] [len=3]`
+    /// or `Node [len=2]`
+    pub depth: Option<usize>,
+    /// maximum elements (or fields) per collection followed by `… N more`
+    pub items: Option<usize>,
+    /// maximum length of string literals in chars
+    pub string: Option<usize>,
+    /// maximum total lines followed by `… N more lines`
+    pub lines: Option<usize>,
+}
+
+impl Limits {
+    /// no limits
+    pub const NONE: Limits = Limits {
+        depth: None,
+        items: None,
+        string: None,
+        lines: None,
+    };
+}
+
+/// sets the limits applied when none are given per call
+pub fn set_limits(limits: Limits) {
+    *LIMITS.write().unwrap_or_else(|error| error.into_inner()) = limits;
+}
+
+/// the limits set via [set_limits] or [Limits::NONE]
+pub fn limits() -> Limits {
+    *LIMITS.read().unwrap_or_else(|error| error.into_inner())
+}
+
+struct Folder<'a> {
+    tokens: Vec<Token<'a>>,
+    index: usize,
+    limits: Limits,
+    output: String,
+}
+
+impl<'a> Folder<'a> {
+    fn new(text: &'a str, limits: Limits) -> Folder<'a> {
+        Folder {
+            tokens: tokenize(text),
+            index: 0,
+            limits,
+            output: String::new(),
+        }
+    }
+
+    fn peek(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.index).copied()
+    }
+
+    /// renders the items at the given depth until the matching close (exclusive)
+    fn sequence(&mut self, depth: usize) {
+        let mut items = 0;
+        let mut expecting_item = true;
+        while let Some(token) = self.peek() {
+            match token.kind {
+                TokenKind::Close(_) => return,
+                TokenKind::Whitespace => self.push(token),
+                TokenKind::Punctuation if token.text == "," => {
+                    expecting_item = true;
+                    self.push(token);
+                }
+                _ => {
+                    if expecting_item {
+                        expecting_item = false;
+                        items += 1;
+                        if depth > 0 && self.limits.items.is_some_and(|max| items > max) {
+                            let (remaining, whitespace) = self.skip_items();
+                            self.output.push_str(&format!("… {remaining} more"));
+                            self.output.push_str(whitespace);
+                            return;
+                        }
+                    }
+                    match token.kind {
+                        TokenKind::Open(_) => self.group(depth),
+                        TokenKind::String => {
+                            self.index += 1;
+                            let string = self.string(token.text);
+                            self.output.push_str(&string);
+                        }
+                        _ => self.push(token),
+                    }
+                }
+            }
+        }
+    }
+
+    fn push(&mut self, token: Token) {
+        self.output.push_str(token.text);
+        self.index += 1;
+    }
+
+    /// renders the group opening at the current token
+    fn group(&mut self, depth: usize) {
+        let Some(open) = self.peek() else {
+            return;
+        };
+        if self.limits.depth.is_some_and(|max| depth >= max) {
+            let name = self.name();
+            self.index += 1;
+            let (length, _) = self.skip_items();
+            self.index += 1;
+            if length == 0 {
+                self.output.push_str(open.text);
+                self.output.push_str(closing(open.text));
+            } else if let Some(name) = name {
+                // `Node {` or `Some(` become `Node [len=2]` or `Some [len=1]`
+                self.output.truncate(self.output.trim_end().len());
+                debug_assert!(self.output.ends_with(name));
+                self.output.push_str(&format!(" [len={length}]"));
+            } else {
+                self.output.push_str(&format!(
+                    "{}…{} [len={length}]",
+                    open.text,
+                    closing(open.text)
+                ));
+            }
+            return;
+        }
+        self.push(open);
+        self.sequence(depth + 1);
+        if let Some(close) = self.peek() {
+            self.push(close);
+        }
+    }
+
+    /// the name of the struct, variant or keyword preceding the group opening
+    /// at the current token if any
+    fn name(&self) -> Option<&'a str> {
+        let mut tokens = self.tokens[..self.index].iter().rev();
+        let token = match tokens.next()? {
+            token if token.kind == TokenKind::Whitespace && !token.text.contains('\n') => {
+                tokens.next()?
+            }
+            token => token,
+        };
+        matches!(token.kind, TokenKind::TypeName | TokenKind::Keyword).then_some(token.text)
+    }
+
+    /// skips the remaining items of the current group up to its close
+    /// (exclusive) and returns their count and the whitespace before the close
+    fn skip_items(&mut self) -> (usize, &'a str) {
+        let mut items = 0;
+        let mut expecting_item = true;
+        let mut nesting = 0usize;
+        let mut whitespace = "";
+        while let Some(token) = self.peek() {
+            if matches!(token.kind, TokenKind::Close(_)) && nesting == 0 {
+                break;
+            }
+            whitespace = "";
+            match token.kind {
+                TokenKind::Whitespace => whitespace = token.text,
+                TokenKind::Close(_) => nesting -= 1,
+                TokenKind::Punctuation if token.text == "," && nesting == 0 => {
+                    expecting_item = true;
+                }
+                kind => {
+                    if nesting == 0 && expecting_item {
+                        items += 1;
+                        expecting_item = false;
+                    }
+                    if matches!(kind, TokenKind::Open(_)) {
+                        nesting += 1;
+                    }
+                }
+            }
+            self.index += 1;
+        }
+        (items, whitespace)
+    }
+
+    fn string(&self, literal: &str) -> String {
+        let content = literal
+            .strip_prefix('"')
+            .and_then(|content| content.strip_suffix('"'))
+            .unwrap_or(literal);
+        let length = content.chars().count();
+        match self.limits.string {
+            Some(max) if length > max => format!(
+                "\"{}…\" [len={length}]",
+                content.chars().take(max).collect::<String>()
+            ),
+            _ => literal.to_string(),
+        }
+    }
+}
+
+fn closing(open: &str) -> &'static str {
+    match open {
+        "(" => ")",
+        "[" => "]",
+        _ => "}",
+    }
+}
+
+/// applies the given limits to the output of `{:#?}` (or `{:?}`)
+pub fn limit_debug(text: &str, limits: Limits) -> String {
+    let mut folder = Folder::new(text, limits);
+    while folder.index < folder.tokens.len() {
+        folder.sequence(0);
+        // unbalanced closing brackets
+        if let Some(token) = folder.peek() {
+            folder.push(token);
+        }
+    }
+    let output = folder.output;
+    match limits.lines {
+        Some(max) if output.lines().count() > max => {
+            let lines = output.lines().collect::<Vec<&str>>();
+            format!(
+                "{}\n… {} more lines",
+                lines[..max].join("\n"),
+                lines.len() - max
+            )
+        }
+        _ => output,
+    }
+}
+
+/// [highlight]s the `{:#?}` rendering of the given value within the given
+/// limits, a `depth` of `0` summarizes the value as i.e.: `Vec<Token> [len=40213]`
+/// while deeper folded regions are summarized by the struct or variant name
+/// in the `{:#?}` output (i.e.: `Node [len=2]`) or else the brackets (i.e.:
+/// `[…] [len=3]`) and empty collections stay as is (i.e.: `[]`)
+pub fn format_debug<T: Debug + ?Sized>(value: &T, limits: Limits) -> String {
+    let text = format!("{value:#?}");
+    if limits.depth == Some(0) {
+        let mut folder = Folder::new(&text, limits);
+        while folder
+            .peek()
+            .is_some_and(|token| !matches!(token.kind, TokenKind::Open(_)))
+        {
+            folder.index += 1;
+        }
+        if folder.peek().is_some() {
+            folder.index += 1;
+            let (length, _) = folder.skip_items();
+            if length == 0 {
+                return highlight(&text);
+            }
+            return highlight(&format!(
+                "{} [len={length}]",
+                short_type_name(std::any::type_name_of_val(value))
+            ));
+        }
+    }
+    highlight(&limit_debug(&text, limits))
+}
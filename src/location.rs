@@ -0,0 +1,256 @@
+//! colorful rendering of source locations shared by [location](crate::location!) and the [diag](crate::diag) functions
+use crate::color::{ansi, auto, fore};
+use crate::function::FunctionName;
+use crate::hyperlink::link_location;
+use std::fmt::Display;
+use std::path::{MAIN_SEPARATOR_STR, Path};
+use std::sync::RwLock;
+
+static LOCATION_STYLE: RwLock<LocationStyle> = RwLock::new(LocationStyle::DEFAULT);
+
+/// how [location](crate::location!) and [filename](crate::filename!) render paths
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PathStyle {
+    /// "{file} in {folder}"
+    #[default]
+    Folded,
+    /// `file!()` unchanged
+    AsIs,
+    /// relative to the workspace root (as `file!()`) or, outside of the
+    /// workspace, to the `CARGO_MANIFEST_DIR` of the crate at compile time
+    WorkspaceRelative,
+    /// file name only
+    FileOnly,
+    /// workspace-relative "{path}:{line}:{column}" in a single color so
+    /// that terminals and editors recognize it
+    Clickable,
+}
+
+/// what [location](crate::location!) and friends show besides the path
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct LocationStyle {
+    pub path: PathStyle,
+    /// shows `column!()` after the line number
+    pub column: bool,
+    /// shows the name of the crate (`CARGO_PKG_NAME`) before the function
+    pub crate_name: bool,
+}
+
+impl LocationStyle {
+    /// "{function} @ {file} in {folder}:{line}"
+    pub const DEFAULT: LocationStyle = LocationStyle {
+        path: PathStyle::Folded,
+        column: false,
+        crate_name: false,
+    };
+}
+
+/// sets how locations are rendered
+pub fn set_location_style(style: LocationStyle) {
+    *LOCATION_STYLE
+        .write()
+        .unwrap_or_else(|error| error.into_inner()) = style;
+}
+
+/// the style set via [set_location_style] or [LocationStyle::DEFAULT]
+pub fn location_style() -> LocationStyle {
+    *LOCATION_STYLE
+        .read()
+        .unwrap_or_else(|error| error.into_inner())
+}
+
+/// crate, file, line and column of a call, see [source_location](crate::source_location!)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SourceLocation<'a> {
+    /// `CARGO_PKG_NAME` of the calling crate, if known
+    pub crate_name: Option<&'a str>,
+    /// `CARGO_MANIFEST_DIR` of the calling crate at compile time, if known
+    pub manifest_dir: Option<&'a str>,
+    /// `file!()`, relative to the workspace root unless outside of it
+    pub file: &'a str,
+    /// `line!()`
+    pub line: u32,
+    /// `column!()`
+    pub column: u32,
+}
+
+impl<'a> SourceLocation<'a> {
+    /// the location of a call within the crate with the given name and
+    /// manifest directory, i.e.: `env!("CARGO_PKG_NAME")` and
+    /// `env!("CARGO_MANIFEST_DIR")` as given by [source_location](crate::source_location!)
+    pub fn new(
+        crate_name: &'a str,
+        manifest_dir: &'a str,
+        file: &'a str,
+        line: u32,
+        column: u32,
+    ) -> SourceLocation<'a> {
+        SourceLocation {
+            crate_name: Some(crate_name),
+            manifest_dir: Some(manifest_dir),
+            file,
+            line,
+            column,
+        }
+    }
+
+    /// the location of a `#[track_caller]` caller, whose crate is only
+    /// known when it is a dependency under `~/.cargo/registry`
+    pub fn caller(location: &'a std::panic::Location<'a>) -> SourceLocation<'a> {
+        SourceLocation {
+            crate_name: registry_crate(location.file()).map(|(name, _, _)| name),
+            manifest_dir: None,
+            file: location.file(),
+            line: location.line(),
+            column: location.column(),
+        }
+    }
+}
+
+/// whether the given text starts with a `{major}.{minor}.{patch}` version
+fn is_version(text: &str) -> bool {
+    let mut parts = text.splitn(3, '.');
+    let numeric = |part: &str| !part.is_empty() && part.bytes().all(|byte| byte.is_ascii_digit());
+    let (Some(major), Some(minor), Some(rest)) = (parts.next(), parts.next(), parts.next()) else {
+        return false;
+    };
+    let patch = rest.split(['-', '+']).next().unwrap_or_default();
+    numeric(major) && numeric(minor) && numeric(patch)
+}
+
+/// name, version and path within the crate of a file of a dependency
+/// under `~/.cargo/registry/src/{index}/{name}-{version}/`
+pub fn registry_crate(file: &str) -> Option<(&str, &str, &str)> {
+    let (_, rest) = file.split_once(&format!(
+        "registry{MAIN_SEPARATOR_STR}src{MAIN_SEPARATOR_STR}"
+    ))?;
+    let (_index, rest) = rest.split_once(MAIN_SEPARATOR_STR)?;
+    let (package, path) = rest.split_once(MAIN_SEPARATOR_STR)?;
+    // names may contain `-{digit}` (i.e.: `foo-2d`) but never a `.`
+    let (name, version) = package
+        .match_indices('-')
+        .map(|(index, _)| (&package[..index], &package[index + 1..]))
+        .find(|(_, version)| is_version(version))?;
+    Some((name, version, path))
+}
+
+/// the given path (i.e.: `file!()`) in the given style, files of registry
+/// dependencies shortened to `{name}@{version}/{path}` unless [PathStyle::AsIs]
+/// and other absolute paths made relative to the given manifest directory
+/// (i.e.: `env!("CARGO_MANIFEST_DIR")` of the crate outside of the workspace)
+pub fn display_path(file: &str, manifest_dir: Option<&str>, style: PathStyle) -> String {
+    let registry = || {
+        registry_crate(file)
+            .map(|(name, version, path)| format!("{name}@{version}{MAIN_SEPARATOR_STR}{path}"))
+    };
+    match style {
+        PathStyle::AsIs => file.to_string(),
+        PathStyle::FileOnly => Path::new(file)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| file.to_string()),
+        PathStyle::Folded | PathStyle::WorkspaceRelative | PathStyle::Clickable => registry()
+            .unwrap_or_else(|| {
+                manifest_dir
+                    .and_then(|root| Path::new(file).strip_prefix(root).ok())
+                    .map(|path| path.to_string_lossy().to_string())
+                    .unwrap_or_else(|| file.to_string())
+            }),
+    }
+}
+
+/// colorfully formats the given path (i.e.: `file!()`) in the
+/// [location_style], linked to the file if [hyperlinks](crate::hyperlink::hyperlinks) are enabled
+pub fn format_filename(location: &SourceLocation) -> String {
+    link_location(
+        style_filename(location),
+        &SourceLocation {
+            line: 1,
+            column: 1,
+            ..*location
+        },
+    )
+}
+
+fn style_filename(location: &SourceLocation) -> String {
+    let style = location_style().path;
+    let path = display_path(location.file, location.manifest_dir, style);
+    match style {
+        PathStyle::Folded => {
+            let mut parts = path
+                .split(MAIN_SEPARATOR_STR)
+                .map(auto)
+                .collect::<Vec<String>>();
+            let filename = parts.pop().unwrap_or_default();
+            if parts.len() > 1 {
+                format!(
+                    "{}{}{}",
+                    filename,
+                    fore(" in ", 7),
+                    parts.join(MAIN_SEPARATOR_STR)
+                )
+            } else {
+                filename
+            }
+        }
+        PathStyle::Clickable => auto(path),
+        _ => path
+            .split(MAIN_SEPARATOR_STR)
+            .map(auto)
+            .collect::<Vec<String>>()
+            .join(MAIN_SEPARATOR_STR),
+    }
+}
+
+/// "{path}:{line}" (or "{path}:{line}:{column}") in the [location_style]
+/// without colors, i.e.: for the banner of [step](crate::step!)
+pub fn plain_location(location: &SourceLocation) -> String {
+    let style = location_style();
+    let path = display_path(location.file, location.manifest_dir, style.path);
+    if style.column || style.path == PathStyle::Clickable {
+        format!("{path}:{}:{}", location.line, location.column)
+    } else {
+        format!("{path}:{}", location.line)
+    }
+}
+
+/// colorfully formats "{crate} › {function} @ {file}:{line}:{column}" in
+/// the [location_style] with "{file}:{line}" linked to the source if
+/// [hyperlinks](crate::hyperlink::hyperlinks) are enabled, without a function (i.e.: from
+/// [std::panic::Location::caller]) "{file}:{line}"
+pub fn format_location(function: Option<&FunctionName>, location: &SourceLocation) -> String {
+    let style = location_style();
+    let mut result = if style.path == PathStyle::Clickable {
+        auto(plain_location(location))
+    } else {
+        let mut result = format!(
+            "{}:{}",
+            style_filename(location),
+            auto(location.line.to_string())
+        );
+        if style.column {
+            result.push_str(&format!(":{}", auto(location.column.to_string())));
+        }
+        result
+    };
+    result = link_location(result, location);
+    if let Some(function) = function {
+        result = format!("{}{}{}", function.format(), ansi(" @ ", 220, 16), result);
+    }
+    if style.crate_name
+        && let Some(crate_name) = location.crate_name
+    {
+        result = format!("{}{}{}", auto(crate_name), fore(" › ", 7), result);
+    }
+    result
+}
+
+/// the tag printed when entering the given location, see [location](crate::location!)
+pub fn format_begin<T: Display>(location: T) -> String {
+    crate::tag!([auto("in function"), location.to_string()].join(" "))
+}
+
+/// the tag printed when leaving the given location, see [location](crate::location!)
+pub fn format_end<T: Display>(location: T) -> String {
+    crate::tag!([auto("from function"), location.to_string()].join(" "))
+}
@@ -1,29 +1,14 @@
 /// colofully prints the "location" of the macro call (function name, filename and line number) in the code
 #[macro_export]
 macro_rules! location {
-    () => {{
-        let location = format!(
-            "{}{}{}:{}",
-            $crate::color::auto($crate::function_name!()),
-            $crate::color::ansi(" @ ", 220, 16),
-            $crate::filename!(),
-            $crate::color::auto(line!().to_string())
-        );
-        location
-    }};
+    () => {
+        $crate::location::format_location(Some(&$crate::function!()), &$crate::source_location!())
+    };
     (begin) => {
-        $crate::tag!([
-            $crate::color::auto(format!("in function")),
-            $crate::location!()
-        ]
-        .join(" "))
+        $crate::location::format_begin($crate::location!())
     };
     (end) => {
-        $crate::tag!([
-            $crate::color::auto(format!("from function")),
-            $crate::location!()
-        ]
-        .join(" "))
+        $crate::location::format_end($crate::location!())
     };
     (unexpected) => {
         [
@@ -34,33 +19,62 @@ macro_rules! location {
         .join(" ")
     };
 }
+/// returns the [SourceLocation](crate::location::SourceLocation) (crate
+/// name, manifest directory, file, line and column) of the macro call
+#[macro_export]
+macro_rules! source_location {
+    () => {
+        $crate::location::SourceLocation::new(
+            env!("CARGO_PKG_NAME"),
+            env!("CARGO_MANIFEST_DIR"),
+            file!(),
+            line!(),
+            column!(),
+        )
+    };
+}
+/// returns a [Scope](crate::scope::Scope) guard which colorfully prints
+/// [location!(begin)](crate::location) right away and
+/// [location!(end)](crate::location) along with the elapsed time when
+/// dropped, i.e.: `let _scope = scope!();`
+#[macro_export]
+macro_rules! scope {
+    () => {
+        $crate::scope::Scope::new($crate::location!())
+    };
+}
+/// colorfully prints [location!(begin)](crate::location) and increments
+/// the call [depth](crate::scope::depth) which indents the output of the
+/// other macros until the matching [exit]
+#[macro_export]
+macro_rules! enter {
+    () => {
+        $crate::scope::enter($crate::location!())
+    };
+}
+/// decrements the call [depth](crate::scope::depth) incremented by [enter]
+/// and colorfully prints [location!(end)](crate::location)
+#[macro_export]
+macro_rules! exit {
+    () => {
+        $crate::scope::exit($crate::location!())
+    };
+}
+/// returns the source lines around the macro call with a line-number
+/// gutter, an arrow at the invoking line and a caret at its column, or
+/// [None] when the source file cannot be read, see [format_snippet](crate::snippet::format_snippet)
+#[macro_export]
+macro_rules! snippet {
+    () => {
+        $crate::snippet::format_snippet(env!("CARGO_MANIFEST_DIR"), file!(), line!(), column!())
+    };
+}
 /// colofully prints the filename of the macro call
 #[macro_export]
 macro_rules! filename {
-    () => {{
-        let mut parts = file!()
-            .split(std::path::MAIN_SEPARATOR_STR)
-            .map(String::from)
-            .map(|part| $crate::color::auto(part.to_string()))
-            .collect::<Vec<String>>();
-        let (folder, filename) = if parts.len() > 1 {
-            let last = parts.remove(parts.len() - 1);
-            let parts = parts.iter().map(Clone::clone).collect::<Vec<String>>();
-            (parts, last)
-        } else {
-            (Vec::<String>::new(), parts[0].to_string())
-        };
-        if folder.len() > 1 {
-            format!(
-                "{}{}{}",
-                filename,
-                $crate::color::fore(" in ", 7),
-                folder.join(std::path::MAIN_SEPARATOR_STR)
-            )
-        } else {
-            filename
-        }
-    }};
+    () => {
+        $crate::location::format_filename(&$crate::source_location!())
+    };
 }
 /// colorfully wraps the given text in "<", ">": "<{text}>"
 #[macro_export]
@@ -278,39 +292,203 @@ macro_rules! tag {
 
 }
 
-/// colorful alternative to [std::dbg]
+/// colorful alternative to [std::dbg] which returns the value of the given
+/// expression or a tuple of the values of the given expressions
+///
+/// supports labels i.e.: `dbg!("parsed header" => header)`, formats i.e.:
+/// `dbg!(fmt = "{:x}", value)` and [Limits](crate::limits::Limits) i.e.:
+/// `dbg!(depth=2, value)`
 #[macro_export]
 macro_rules! dbg {
-    ($arg:expr $(,)? ) => {{
-        eprintln!("{}", $crate::format_dbg_location!($arg));
-        $arg
-    }};
-    ($( $arg:expr ),* $(,)? ) => {{
-        eprintln!("{}", $crate::format_dbg_location!($($arg),*));
+    (@emit $($line:expr),+) => {
+        $crate::output::emit($crate::diag::format_dbg($crate::location!(), &[$($line),+]))
+    };
+    (@limits $limits:ident; $key:ident = $value:expr, $($rest:tt)+) => {{
+        $limits.$key = Some($value);
+        $crate::dbg!(@limits $limits; $($rest)+)
+    }};
+    (@limits $limits:ident; $arg:expr $(,)?) => {
+        match $arg {
+            value => {
+                $crate::dbg!(@emit $crate::format_dbg!(@labeled concat!("&", stringify!($arg)), &value, $limits));
+                value
+            }
+        }
+    };
+    (@limits $limits:ident; $( $arg:expr ),+ $(,)?) => {{
+        let mut lines = Vec::<String>::new();
+        let values = ($({
+            let value = $arg;
+            lines.push($crate::format_dbg!(@labeled concat!("&", stringify!($arg)), &value, $limits));
+            value
+        },)+);
+        $crate::dbg!(@emit lines.join("\n"));
+        values
+    }};
+    () => {
+        $crate::output::emit($crate::location!())
+    };
+    ($label:literal => $arg:expr $(,)?) => {
+        match $arg {
+            value => {
+                $crate::dbg!(@emit $crate::format_dbg!(@labeled $label, &value, $crate::limits::limits()));
+                value
+            }
+        }
+    };
+    ($( $label:literal => $arg:expr ),+ $(,)?) => {{
+        let mut lines = Vec::<String>::new();
+        let values = ($({
+            let value = $arg;
+            lines.push($crate::format_dbg!(@labeled $label, &value, $crate::limits::limits()));
+            value
+        },)+);
+        $crate::dbg!(@emit lines.join("\n"));
+        values
+    }};
+    (fmt = $fmt:literal, $arg:expr $(,)?) => {
+        match $arg {
+            value => {
+                $crate::dbg!(@emit $crate::format_dbg!(@fmt $fmt, concat!("&", stringify!($arg)), &value));
+                value
+            }
+        }
+    };
+    (fmt = $fmt:literal, $( $arg:expr ),+ $(,)?) => {{
+        let mut lines = Vec::<String>::new();
+        let values = ($({
+            let value = $arg;
+            lines.push($crate::format_dbg!(@fmt $fmt, concat!("&", stringify!($arg)), &value));
+            value
+        },)+);
+        $crate::dbg!(@emit lines.join("\n"));
+        values
+    }};
+    ($key:ident = $value:expr, $($rest:tt)+) => {{
+        let mut limits = $crate::limits::limits();
+        $crate::dbg!(@limits limits; $key = $value, $($rest)+)
+    }};
+    ($( $arg:expr ),+ $(,)? ) => {{
+        let limits = $crate::limits::limits();
+        $crate::dbg!(@limits limits; $($arg),+)
     }};
 }
 
+/// colorfully prints the given error along with its chain of sources and
+/// backtrace via [format_error](crate::error::format_error) and returns it
+///
+/// > accepts anything which derefs to an error, i.e.: `Box<dyn Error>`
+#[macro_export]
+macro_rules! dbg_err {
+    ($error:expr $(,)?) => {
+        match $error {
+            error => {
+                $crate::dbg!(@emit $crate::indent!(format!(
+                    "{} =\n{}\n",
+                    $crate::color::auto(stringify!($error)),
+                    $crate::indent!($crate::error::format_error({
+                        use $crate::error::AsDynError as _;
+                        error.as_dyn_error()
+                    })),
+                )));
+                error
+            }
+        }
+    };
+}
+
+/// colorfully formats expressions and their values within the
+/// [limits](crate::limits::limits) or the ones given per call, i.e.:
+/// `format_dbg!(depth=2, items=10, value)`, with labels i.e.:
+/// `format_dbg!("header" => value)` or formats i.e.: `format_dbg!(fmt = "{:x}", value)`
 #[macro_export]
 macro_rules! format_dbg {
-    ($arg:expr $(,)? ) => {{
+    (@labeled $label:expr, $value:expr, $limits:expr) => {
         $crate::indent!(
-                format!(
-                    "{} = {}\n",
-                    $crate::color::auto(stringify!(&$arg)),
-                    $crate::color::auto(format!("{:#?}", &$arg))))
-
+            format!(
+                "{} = {}\n",
+                $crate::color::auto($label),
+                $crate::limits::format_debug($value, $limits)))
+    };
+    (@fmt $fmt:literal, $label:expr, $value:expr) => {
+        $crate::indent!(
+            format!(
+                "{} = {}\n",
+                $crate::color::auto($label),
+                $crate::color::auto(format!($fmt, $value))))
+    };
+    (@limits $limits:ident; $key:ident = $value:expr, $($rest:tt)+) => {{
+        $limits.$key = Some($value);
+        $crate::format_dbg!(@limits $limits; $($rest)+)
     }};
-    ($( $arg:expr ),* $(,)? ) => {{
-        [$($crate::format_dbg!($arg)),*].join("\n")
+    (@limits $limits:ident; $( $arg:expr ),+ $(,)?) => {{
+        [$($crate::format_dbg!(@labeled concat!("&", stringify!($arg)), &$arg, $limits)),+].join("\n")
+    }};
+    ($( $label:literal => $arg:expr ),+ $(,)?) => {{
+        [$($crate::format_dbg!(@labeled $label, &$arg, $crate::limits::limits())),+].join("\n")
+    }};
+    (fmt = $fmt:literal, $( $arg:expr ),+ $(,)?) => {{
+        [$($crate::format_dbg!(@fmt $fmt, concat!("&", stringify!($arg)), &$arg)),+].join("\n")
+    }};
+    ($key:ident = $value:expr, $($rest:tt)+) => {{
+        let mut limits = $crate::limits::limits();
+        $crate::format_dbg!(@limits limits; $key = $value, $($rest)+)
+    }};
+    ($( $arg:expr ),+ $(,)? ) => {{
+        let limits = $crate::limits::limits();
+        $crate::format_dbg!(@limits limits; $($arg),+)
     }};
 }
+/// [format_dbg] between [location!(begin)](crate::location) and [location!(end)](crate::location)
 #[macro_export]
 macro_rules! format_dbg_location {
-    ($arg:expr $(,)? ) => {{
-        format!("{}", $crate::color::reset([$crate::location!(begin), $crate::format_dbg!($arg), $crate::location!(end)].join("\n")))
+    ($($arg:tt)+) => {{
+        $crate::diag::format_dbg($crate::location!(), &[$crate::format_dbg!($($arg)+)])
     }};
-    ($( $arg:expr ),* $(,)? ) => {{
-        [$crate::location!(begin), $($crate::format_dbg!($arg)),*, $crate::location!(end)].join("\n")
+}
+
+/// colorful alternative to [std::dbg] which also shows the type, size,
+/// alignment and address of each expression via [format_meta](crate::meta::format_meta)
+/// and returns the value of a single expression or a tuple of the values of
+/// the given expressions
+///
+/// > like [std::dbg], each expression is evaluated once and moved, so the
+/// > address is the one of the moved value, pass i.e.: `&place` for the
+/// > address of a place
+#[macro_export]
+macro_rules! dbg_meta {
+    (@format $arg:expr, $value:expr) => {
+        $crate::indent!(format!(
+            "{} = {}\n{}\n",
+            $crate::color::auto(concat!("&", stringify!($arg))),
+            $crate::limits::format_debug($value, $crate::limits::limits()),
+            $crate::indent!($crate::meta::format_meta($value)),
+        ))
+    };
+    (@emit $($line:expr),+) => {
+        $crate::output::emit($crate::color::reset([
+            $crate::location!(begin),
+            $($line,)+
+            $crate::location!(end),
+        ].join("\n")))
+    };
+    ($arg:expr $(,)?) => {
+        match $arg {
+            value => {
+                $crate::dbg_meta!(@emit $crate::dbg_meta!(@format $arg, &value));
+                value
+            }
+        }
+    };
+    ($( $arg:expr ),+ $(,)?) => {{
+        let mut lines = Vec::<String>::new();
+        let values = ($({
+            let value = $arg;
+            lines.push($crate::dbg_meta!(@format $arg, &value));
+            value
+        },)+);
+        $crate::dbg_meta!(@emit lines.join("\n"));
+        values
     }};
 }
 
@@ -328,11 +506,11 @@ macro_rules! indent {
         $crate::indent!(4, $obj)
     }};
 }
-/// indents an implementor of [std::fmt::Debug]
+/// indents an implementor of [std::fmt::Debug] syntax-highlighted via [format_debug](crate::limits::format_debug)
 #[macro_export]
 macro_rules! indent_objdump {
     ($indentation:literal, $obj:expr) => {{
-        format!("{:#?}", $obj)
+        $crate::limits::format_debug(&$obj, $crate::limits::limits())
             .lines()
             .map(|line| format!("{}{}", " ".repeat($indentation), line))
             .collect::<Vec<String>>()
@@ -343,20 +521,25 @@ macro_rules! indent_objdump {
     }};
 }
 
-/// returns a [String] with the name of the function calling the macro
+/// returns a [String] with the name of the function calling the macro,
+/// i.e.: `<Bar as Foo>::go λ` within a closure in a trait method, see [function]
 #[macro_export]
 macro_rules! function_name {
+    () => {{
+        $crate::function!().to_string()
+    }};
+}
+/// returns the [FunctionName](crate::function::FunctionName) of the
+/// function calling the macro, split into its module, impl and trait
+/// context and closure depth
+#[macro_export]
+macro_rules! function {
     () => {{
         fn f() {}
         fn type_name_of<T>(_: T) -> &'static str {
             std::any::type_name::<T>()
         }
-        let name = type_name_of(f);
-        let name = name
-            .strip_suffix("::f")
-            .unwrap()
-            .replace(format!("{}::", module_path!()).as_str(), "");
-        name
+        $crate::function::FunctionName::parse(type_name_of(f), module_path!())
     }};
 }
 
@@ -364,58 +547,40 @@ macro_rules! function_name {
 #[macro_export]
 macro_rules! step {
     ($text:expr $(,)?) => {{
-        $crate::step!(length=$crate::color::term_cols(), $text)
+        $crate::step!(length=$crate::output::columns(), $text)
     }};
     (fg=$fg:expr, $text:expr $(,)?) => {{
-        let fg=$crate::color::wrap($fg as usize) as usize;
-        $crate::step!(bg=fg, fg=$crate::color::invert_ansi(fg), length=$crate::color::term_cols(), $text)
+        let fg=$crate::parse::IntoAnsi::into_ansi($fg) as usize;
+        $crate::step!(bg=fg, fg=$crate::color::invert_ansi(fg), length=$crate::output::columns(), $text)
     }};
     (bg=$bg:expr, fg=$fg:expr, $text:expr $(,)?) => {{
-        let bg=$crate::color::wrap($bg as usize) as usize;
-        let fg=$crate::color::wrap($fg as usize) as usize;
-        $crate::step!(bg=bg, fg=fg, length=$crate::color::term_cols(), $text)
+        let bg=$crate::parse::IntoAnsi::into_ansi($bg) as usize;
+        let fg=$crate::parse::IntoAnsi::into_ansi($fg) as usize;
+        $crate::step!(bg=bg, fg=fg, length=$crate::output::columns(), $text)
     }};
     (length=$length:expr, $text:expr $(,)?) => {{
         let (bg, fg) = $crate::color::couple(line!() as usize);
         $crate::step!(bg=bg, fg=fg, length=$length, $text)
     }};
+    (gradient, $text:expr $(,)?) => {{
+        let (bg, fg) = $crate::color::couple(line!() as usize);
+        $crate::step!(bg=bg, fg=fg, length=$crate::output::columns(), gradient=true, $text)
+    }};
     (bg=$bg:expr, fg=$fg:expr, length=$length:expr, $text:expr $(,)?) => {{
-        let bg = $crate::color::wrap($bg as usize) as usize;
-        let fg = $crate::color::wrap($fg as usize) as usize;
-
-        let text = $text.to_string();
-        let bar = $crate::color::ansi(
-            " ".repeat($length),
-            $fg as usize,
-            $bg as usize,
-        );
-        eprintln!(
-            "\n{}",
-            [
-                bar.clone(),
-                $crate::color::ansi(
-                    $crate::color::pad_columns(
-                        [
-                            $crate::function_name!(),
-                            [
-                                file!().to_string(),
-                                line!().to_string(),
-                            ].join(":")
-                        ].join(" ").to_string()
-                    ),
-                    $fg as usize,
-                    $bg as usize,
-                ),
-                $crate::color::ansi(
-                    $crate::color::pad_columns(
-                        if text.is_empty() { String::new() } else { format!("{}", text) }
-                    ),
-                    $bg as usize,
-                    $fg as usize,
-                ),
-                bar.clone(),
-            ].join("\n")
-        );
+        $crate::step!(bg=$bg, fg=$fg, length=$length, gradient=false, $text)
+    }};
+    (bg=$bg:expr, fg=$fg:expr, length=$length:expr, gradient=$gradient:expr, $text:expr $(,)?) => {{
+        let bg = $crate::parse::IntoAnsi::into_ansi($bg) as usize;
+        let fg = $crate::parse::IntoAnsi::into_ansi($fg) as usize;
+        let location = $crate::source_location!();
+        let header = [
+            $crate::function_name!(),
+            $crate::hyperlink::link_location($crate::location::plain_location(&location), &location),
+        ].join(" ");
+        $crate::output::emit($crate::snippet::append_snippet(
+            $crate::diag::format_step(&header, &$text.to_string(), fg, bg, $length, $gradient),
+            env!("CARGO_MANIFEST_DIR"), file!(), line!(), column!(),
+        ));
     }};
     (length=$length:expr, $text:expr, $( $arg:expr ),* $(,)? ) => {{
         $crate::step!(length=$length, format_args!($text, $($arg,)*))
@@ -424,29 +589,49 @@ macro_rules! step {
         $crate::step!("")
     }};
 }
+/// evaluates the given expression, prints how long it took in a
+/// [step]-style banner colored by [duration_color](crate::timing::duration_color)
+/// and returns its value
+#[macro_export]
+macro_rules! time {
+    (label=$label:expr, $expr:expr $(,)?) => {{
+        let start = std::time::Instant::now();
+        let value = $expr;
+        let elapsed = start.elapsed();
+        let color = $crate::timing::duration_color(elapsed) as usize;
+        $crate::step!(
+            fg=color,
+            format!("{} took {}", $label, $crate::timing::humanize_duration(elapsed))
+        );
+        value
+    }};
+    ($expr:expr $(,)?) => {{
+        $crate::time!(label=stringify!($expr), $expr)
+    }};
+}
 /// colorfully steps through code debugging given expressions
 #[macro_export]
 macro_rules! step_dbg {
     (bg=$bg:expr, fg=$fg:expr, length=$length:expr, $($arg:expr),* $(,)?) => {{
-        let bg=$crate::color::wrap($bg as usize);
-        let fg=$crate::color::wrap($fg as usize);
+        let bg=$crate::parse::IntoAnsi::into_ansi($bg);
+        let fg=$crate::parse::IntoAnsi::into_ansi($fg);
         let text = format!("{}{}", $crate::reset(""), [
-            $($crate::indent!(format!("{} = {}", $crate::color::auto(stringify!($arg)), $crate::color::auto(format!("{:#?}", $arg))))),*
+            $($crate::indent!(format!("{} = {}", $crate::color::auto(stringify!($arg)), $crate::limits::format_debug(&$arg, $crate::limits::limits())))),*
         ].join("\n"));
         $crate::step!(bg=bg, fg=fg, length=$length, text);
     }};
     (bg=$bg:expr, fg=$fg:expr, $($arg:expr),* $(,)?) => {{
-        let bg=$crate::color::wrap($bg as usize);
-        let fg=$crate::color::wrap($fg as usize);
-        $crate::step_dbg!(bg=bg, fg=fg, length=$crate::color::term_cols(), $($arg),*)
+        let bg=$crate::parse::IntoAnsi::into_ansi($bg);
+        let fg=$crate::parse::IntoAnsi::into_ansi($fg);
+        $crate::step_dbg!(bg=bg, fg=fg, length=$crate::output::columns(), $($arg),*)
     }};
     (fg=$fg:expr, $($arg:expr),* $(,)?) => {{
-        let fg=$crate::color::wrap($fg as usize) as usize;
-        $crate::step_dbg!(bg=fg, fg=$crate::color::invert_ansi(fg), length=$crate::color::term_cols(), $($arg),*)
+        let fg=$crate::parse::IntoAnsi::into_ansi($fg) as usize;
+        $crate::step_dbg!(bg=fg, fg=$crate::color::invert_ansi(fg), length=$crate::output::columns(), $($arg),*)
     }};
     ($($arg:expr),* $(,)?) => {{
         let fg=$crate::color::wrap(line!() as usize) as usize;
-        $crate::step_dbg!(bg=fg, fg=$crate::color::invert_ansi(fg), length=$crate::color::term_cols(), $($arg),*)
+        $crate::step_dbg!(bg=fg, fg=$crate::color::invert_ansi(fg), length=$crate::output::columns(), $($arg),*)
     }};
     () => {{
         $crate::step!("")
@@ -454,6 +639,9 @@ macro_rules! step_dbg {
 }
 
 /// colorfully prints an admonition
+///
+/// > the color must be an integer or a color string literal (see
+/// > [IntoAnsi](crate::parse::IntoAnsi)), anything else fails to compile
 #[macro_export]
 macro_rules! admonition {
     ($color:literal, $message:expr) => {
@@ -467,42 +655,22 @@ macro_rules! admonition {
         $crate::admonition!($color, title=$title, "{}", $message);
     };
     ($color:literal, title=$title:literal, $format:literal, $($arg:expr),* $(,)?) => {{
-        use crate::color;
-        eprintln!(
-            "\n{}",
-            [
-                color::ansi(
-                    format!("{}:{} {}", crate::function_name!(), line!(), $title),
-                    color::invert_ansi($color).into(),
-                    $color,
-                ),
-                color::ansi(
-                    format!($format, $($arg),*),
-                    $color,
-                    color::invert_ansi($color).into(),
-                )
-            ]
-            .join(" ")
-        );
+        const { $crate::parse::check_color_literal(stringify!($color)) };
+        let color = $crate::parse::IntoAnsi::into_ansi($color) as usize;
+        let header = format!("{}:{} {}", $crate::function_name!(), line!(), $title);
+        $crate::output::emit($crate::snippet::append_snippet(
+            $crate::diag::format_admonition(&header, &format!($format, $($arg),*), color),
+            env!("CARGO_MANIFEST_DIR"), file!(), line!(), column!(),
+        ));
     }};
     ($color:literal, $format:literal, $($arg:expr),* $(,)?) => {{
-        use crate::color;
-        eprintln!(
-            "\n{}",
-            [
-                color::ansi(
-                    format!("{}:{}", crate::function_name!(), line!()),
-                    color::invert_ansi($color).into(),
-                    $color,
-                ),
-                color::ansi(
-                    format!($format, $($arg),*),
-                    $color,
-                    color::invert_ansi($color).into(),
-                )
-            ]
-            .join(" ")
-        );
+        const { $crate::parse::check_color_literal(stringify!($color)) };
+        let color = $crate::parse::IntoAnsi::into_ansi($color) as usize;
+        let header = format!("{}:{}", $crate::function_name!(), line!());
+        $crate::output::emit($crate::snippet::append_snippet(
+            $crate::diag::format_admonition(&header, &format!($format, $($arg),*), color),
+            env!("CARGO_MANIFEST_DIR"), file!(), line!(), column!(),
+        ));
     }};
 }
 
@@ -639,7 +807,7 @@ macro_rules! dbg_bytes_str {
     ($slice:expr $(,)? ) => {{
         use $crate::color::{auto, back, fore, from_display, pad};
         use $crate::indent;
-        eprintln!(
+        $crate::output::emit(format!(
             "\n{}",
             [
                 $crate::location!(begin),
@@ -650,7 +818,7 @@ macro_rules! dbg_bytes_str {
                 $crate::location!(end),
             ]
             .join("\n")
-        );
+        ));
         $slice
     }};
 }
@@ -666,16 +834,60 @@ macro_rules! dbg_bytes_if_str {
     };
 }
 /// colorfully formats a slice or vector of [u8] as hex => binary => decimal (=> char (if ascii))
+///
+/// `format_bytes!(dump, slice)` (or `format_bytes!(dump, slice, offset)`)
+/// formats it as a hexdump instead: offset, hex and ASCII columns of 16
+/// bytes per line, each byte colored as by [format_byte](crate::format_byte!)
 #[macro_export]
 macro_rules! format_bytes {
+    (dump, $slice:expr $(,)? ) => {
+        $crate::format_bytes!(dump, $slice, 0usize)
+    };
+    (dump, $slice:expr, $offset:expr $(,)? ) => {{
+        let offset: usize = $offset;
+        $slice
+            .chunks(16)
+            .enumerate()
+            .map(|(index, chunk)| {
+                let hex = (0..16)
+                    .map(|column| match chunk.get(column) {
+                        Some(byte) => $crate::color::fore(
+                            format!("{byte:02x}"),
+                            $crate::color::from_byte(*byte).into(),
+                        ),
+                        None => "  ".to_string(),
+                    })
+                    .collect::<Vec<String>>();
+                let ascii = chunk
+                    .iter()
+                    .map(|byte| {
+                        let c = if byte.is_ascii_graphic() || *byte == b' ' {
+                            char::from(*byte)
+                        } else {
+                            '.'
+                        };
+                        $crate::color::fore(c, $crate::color::from_byte(*byte).into())
+                    })
+                    .collect::<String>();
+                format!(
+                    "{}  {}  {}  {}",
+                    $crate::color::fore(format!("{:08x}", offset + index * 16), 237),
+                    hex[..8].join(" "),
+                    hex[8..].join(" "),
+                    ascii
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }};
     ($slice:expr $(,)? ) => {
-        $crate::format_bytes!($slice, " => ");
+        $crate::format_bytes!($slice, " => ")
     };
     (hex, $slice:expr $(,)? ) => {
-        $crate::format_bytes!(hex, $slice, " => ");
+        $crate::format_bytes!(hex, $slice, " => ")
     };
     (bin, $slice:expr $(,)? ) => {
-        $crate::format_bytes!(bin, $slice, " => ");
+        $crate::format_bytes!(bin, $slice, " => ")
     };
     ($slice:expr, $sep:literal $(,)? ) => {{
         [
@@ -770,7 +982,7 @@ macro_rules! format_bytes {
 #[macro_export]
 macro_rules! format_bytes_str {
     ($slice:expr $(,)? ) => {
-        $crate::format_bytes_str!($slice, " => ");
+        $crate::format_bytes_str!($slice, " => ")
     };
     ($slice:expr, $sep:literal $(,)? ) => {{
         [
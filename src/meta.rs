@@ -0,0 +1,71 @@
+//! type name, size, alignment and address of values, see [dbg_meta](crate::dbg_meta)
+use crate::color::{auto, fore};
+use crate::theme::theme;
+
+fn is_path_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == ':'
+}
+
+/// strips the module path of each segment of a type name the same way
+/// [function_name](crate::function_name) strips module paths (i.e.:
+/// `alloc::vec::Vec<my::Token>` => `Vec<Token>`)
+pub fn short_type_name(name: &str) -> String {
+    let mut result = String::new();
+    let mut segment = String::new();
+    for c in name.chars() {
+        if is_path_char(c) {
+            segment.push(c);
+        } else {
+            result.push_str(segment.rsplit("::").next().unwrap_or_default());
+            segment.clear();
+            result.push(c);
+        }
+    }
+    result.push_str(segment.rsplit("::").next().unwrap_or_default());
+    result
+}
+
+/// colorizes each type of a (generic) type name via [auto] and the
+/// punctuation with [Theme::punctuation](crate::theme::Theme::punctuation)
+pub fn format_type_name(name: &str) -> String {
+    let punctuation = theme().punctuation;
+    let mut result = String::new();
+    let mut segment = String::new();
+    let mut flush = |segment: &mut String, result: &mut String| {
+        if !segment.is_empty() {
+            result.push_str(&auto(&segment));
+            segment.clear();
+        }
+    };
+    for c in name.chars() {
+        if is_path_char(c) {
+            segment.push(c);
+        } else {
+            flush(&mut segment, &mut result);
+            if c == ' ' {
+                result.push(c);
+            } else {
+                result.push_str(&fore(c, punctuation.into()));
+            }
+        }
+    }
+    flush(&mut segment, &mut result);
+    result
+}
+
+/// colorfully formats the [short_type_name], [size_of_val](std::mem::size_of_val),
+/// [align_of_val](std::mem::align_of_val) and address of the given value
+pub fn format_meta<T: ?Sized>(value: &T) -> String {
+    let address = format!("{:p}", std::ptr::from_ref(value).cast::<()>());
+    [
+        format_type_name(&short_type_name(std::any::type_name_of_val(value))),
+        format!("{}{}", fore("size=", 7), auto(std::mem::size_of_val(value))),
+        format!(
+            "{}{}",
+            fore("align=", 7),
+            auto(std::mem::align_of_val(value))
+        ),
+        format!("{}{}", fore("@ ", 7), auto(address)),
+    ]
+    .join(" ")
+}
@@ -0,0 +1,146 @@
+//! prints diagnostics to stderr indented by the current call [depth](crate::scope::depth)
+use crate::color::{color_enabled, fore, rgb_to_byte, strip_ansi, term_cols};
+use crate::gradient::rainbow_colors;
+use crate::identity::format_prefix;
+use crate::scope::depth;
+use crate::timestamp::{format_timestamp, timestamp_width};
+use std::fmt::Display;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// number of columns of each level of indentation
+pub const INDENTATION: usize = 4;
+/// number of colors [guide_color] cycles through
+const GUIDE_COLORS: usize = 6;
+
+static TREE_GUIDES: AtomicBool = AtomicBool::new(false);
+
+/// draws tree guides (`│`, `├`, `└`) colored by [guide_color] in the indentation of each level
+pub fn set_tree_guides(enabled: bool) {
+    TREE_GUIDES.store(enabled, Ordering::Relaxed);
+}
+
+/// whether tree guides are drawn, see [set_tree_guides]
+pub fn tree_guides() -> bool {
+    TREE_GUIDES.load(Ordering::Relaxed)
+}
+
+/// ANSI-256 color of the tree guide of the given depth level
+pub fn guide_color(level: usize) -> u8 {
+    rgb_to_byte(rainbow_colors(GUIDE_COLORS)[level % GUIDE_COLORS])
+}
+
+/// indentation of the lines printed at the given depth
+pub fn indentation(depth: usize) -> String {
+    if tree_guides() {
+        (0..depth)
+            .map(|level| fore("│   ", guide_color(level).into()))
+            .collect()
+    } else {
+        " ".repeat(INDENTATION * depth)
+    }
+}
+
+/// indentation of the line which opens (or, if `last`, closes) the given depth level
+pub fn branch(depth: usize, last: bool) -> String {
+    if tree_guides() {
+        format!(
+            "{}{}",
+            indentation(depth),
+            fore(
+                if last { "└── " } else { "├── " },
+                guide_color(depth).into()
+            )
+        )
+    } else {
+        indentation(depth)
+    }
+}
+
+/// number of columns available to a line at the current [depth] after the
+/// [format_timestamp] and the [format_prefix]
+pub fn columns() -> usize {
+    term_cols()
+        .saturating_sub(INDENTATION * depth())
+        .saturating_sub(timestamp_width())
+        .saturating_sub(strip_ansi(format_prefix()).chars().count())
+}
+
+/// prefixes each line of the given text with the given indentation
+/// (without trailing whitespace on empty lines)
+pub fn indent_lines<T: Display>(text: T, indentation: &str) -> String {
+    text.to_string()
+        .lines()
+        .map(|line| {
+            if line.is_empty() {
+                indentation.trim_end().to_string()
+            } else {
+                format!("{indentation}{line}")
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// the given text without colors unless [color_enabled]
+pub fn colored<T: Display>(text: T) -> String {
+    if color_enabled() {
+        text.to_string()
+    } else {
+        strip_ansi(text)
+    }
+}
+
+/// writes the given text to stderr at once (i.e.: a single [eprintln]) so
+/// that blocks of lines from different threads never interleave, each line
+/// prefixed via [format_prefix] after the [format_timestamp] column
+fn write_block(text: String) {
+    let prefix = format_prefix();
+    let timestamp = format_timestamp();
+    let text = if prefix.is_empty() && timestamp.is_empty() {
+        text
+    } else {
+        // the timestamp leads the first non-empty line (i.e.: not the blank
+        // line before a banner), the others are aligned with it
+        let blank = " ".repeat(strip_ansi(&timestamp).chars().count());
+        let first = text.split('\n').position(|line| !line.is_empty());
+        text.split('\n')
+            .enumerate()
+            .map(|(index, line)| {
+                let timestamp = if Some(index) == first.or(Some(0)) {
+                    &timestamp
+                } else {
+                    &blank
+                };
+                format!("{timestamp}{prefix}{line}")
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    };
+    eprintln!("{}", colored(text));
+}
+
+/// prints the given text to stderr indented by the current [depth]
+pub fn emit<T: Display>(text: T) {
+    write_block(indent_lines(text, &indentation(depth())));
+}
+
+/// prints the given text to stderr as the line which opens (or, if `last`,
+/// closes) the given depth level, see [branch]
+pub fn emit_branch<T: Display>(text: T, depth: usize, last: bool) {
+    emit_branch_at(text, depth, last, depth + 1);
+}
+
+/// [emit_branch] with the lines after the first indented by the given depth
+/// (i.e.: detached [Scope](crate::scope::Scope)s, which leave the depth alone)
+pub(crate) fn emit_branch_at<T: Display>(text: T, depth: usize, last: bool, lines_depth: usize) {
+    let text = text.to_string();
+    let mut lines = text.lines();
+    let first = lines.next().unwrap_or_default();
+    let rest = lines.collect::<Vec<&str>>().join("\n");
+    let mut block = format!("{}{}", branch(depth, last), first);
+    if !rest.is_empty() {
+        block.push('\n');
+        block.push_str(&indent_lines(rest, &indentation(lines_depth)));
+    }
+    write_block(block);
+}
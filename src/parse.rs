@@ -0,0 +1,568 @@
+//! parsing of colors from hex, CSS names, `rgb()`, `hsl()` and `ansi()` strings
+use crate::color::{bg, bg_rgb, fg, fg_rgb, get_ansi_rgb, rgb_to_byte, truecolor, wrap};
+use crate::cvd::rgb_distance;
+use crate::space::hsl_to_rgb;
+use std::fmt::Display;
+
+/// a color given either as an ANSI-256 index or as a `[red, green, blue]` triple
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    Ansi(u8),
+    Rgb([u8; 3]),
+}
+
+impl Color {
+    /// the ANSI-256 color nearest to this color via [rgb_to_byte]
+    pub fn ansi(&self) -> u8 {
+        match self {
+            Color::Ansi(color) => *color,
+            Color::Rgb(rgb) => rgb_to_byte(*rgb),
+        }
+    }
+    /// the `[red, green, blue]` triple of this color via [get_ansi_rgb]
+    pub fn rgb(&self) -> [u8; 3] {
+        match self {
+            Color::Ansi(color) => get_ansi_rgb(*color as usize),
+            Color::Rgb(rgb) => *rgb,
+        }
+    }
+    /// colorize the foreground of the given text without resetting,
+    /// in 24-bit color when supported (see [truecolor])
+    pub fn fg<T: Display>(&self, text: T) -> String {
+        match self {
+            Color::Rgb(rgb) if truecolor() => fg_rgb(text, *rgb),
+            _ => fg(text, self.ansi() as usize),
+        }
+    }
+    /// colorize the background of the given text without resetting,
+    /// in 24-bit color when supported (see [truecolor])
+    pub fn bg<T: Display>(&self, text: T) -> String {
+        match self {
+            Color::Rgb(rgb) if truecolor() => bg_rgb(text, *rgb),
+            _ => bg(text, self.ansi() as usize),
+        }
+    }
+}
+
+impl std::str::FromStr for Color {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Color, String> {
+        parse_color(s).ok_or_else(|| format!("invalid color: {s:#?}"))
+    }
+}
+
+/// parses a color from `#rrggbb`, `#rgb`, CSS/X11 color names, `rgb(…)`, `hsl(…)` or `ansi(N)`
+pub fn parse_color(text: &str) -> Option<Color> {
+    let text = text.trim().to_lowercase();
+    if let Some(hex) = text.strip_prefix('#') {
+        return parse_hex(hex).map(Color::Rgb);
+    }
+    if let Some(args) = function_args(&text, "ansi") {
+        return match args.as_slice() {
+            [index] => index.parse::<u8>().ok().map(Color::Ansi),
+            _ => None,
+        };
+    }
+    if let Some(args) = function_args(&text, "rgb").or_else(|| function_args(&text, "rgba")) {
+        return match args.as_slice() {
+            [r, g, b] | [r, g, b, _] => Some(Color::Rgb([
+                parse_channel(r)?,
+                parse_channel(g)?,
+                parse_channel(b)?,
+            ])),
+            _ => None,
+        };
+    }
+    if let Some(args) = function_args(&text, "hsl").or_else(|| function_args(&text, "hsla")) {
+        return match args.as_slice() {
+            [h, s, l] | [h, s, l, _] => Some(Color::Rgb(hsl_to_rgb([
+                parse_hue(h)?,
+                parse_percentage(s)?,
+                parse_percentage(l)?,
+            ]))),
+            _ => None,
+        };
+    }
+    if let Ok(index) = text.parse::<u8>() {
+        return Some(Color::Ansi(index));
+    }
+    let name = text
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '_' | '-'))
+        .collect::<String>();
+    CSS_COLORS
+        .iter()
+        .find(|(css, _)| *css == name)
+        .map(|(_, rgb)| Color::Rgb(*rgb))
+}
+
+/// returns the CSS color name nearest to the given ANSI-256 color
+pub fn color_name(color: usize) -> &'static str {
+    let rgb = get_ansi_rgb(color);
+    CSS_COLORS
+        .iter()
+        .min_by(|(_, a), (_, b)| rgb_distance(rgb, *a).total_cmp(&rgb_distance(rgb, *b)))
+        .map(|(name, _)| *name)
+        .unwrap_or_default()
+}
+
+fn parse_hex(hex: &str) -> Option<[u8; 3]> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    match hex.len() {
+        3 => {
+            let mut rgb = [0u8; 3];
+            for (channel, digit) in rgb.iter_mut().zip(hex.chars()) {
+                *channel = u8::from_str_radix(&digit.to_string().repeat(2), 16).ok()?;
+            }
+            Some(rgb)
+        }
+        6 => Some([
+            u8::from_str_radix(&hex[0..2], 16).ok()?,
+            u8::from_str_radix(&hex[2..4], 16).ok()?,
+            u8::from_str_radix(&hex[4..6], 16).ok()?,
+        ]),
+        _ => None,
+    }
+}
+
+fn function_args(text: &str, name: &str) -> Option<Vec<String>> {
+    let args = text
+        .strip_prefix(name)?
+        .trim_start()
+        .strip_prefix('(')?
+        .strip_suffix(')')?;
+    Some(
+        args.split([',', ' ', '/'])
+            .map(str::trim)
+            .filter(|arg| !arg.is_empty())
+            .map(String::from)
+            .collect(),
+    )
+}
+
+fn parse_channel(arg: &str) -> Option<u8> {
+    match arg.strip_suffix('%') {
+        Some(percentage) => {
+            let percentage = percentage.parse::<f64>().ok()?;
+            Some((percentage.clamp(0.0, 100.0) * 2.55).round() as u8)
+        }
+        None => Some(arg.parse::<f64>().ok()?.clamp(0.0, 255.0).round() as u8),
+    }
+}
+
+fn parse_hue(arg: &str) -> Option<f64> {
+    let hue = arg.strip_suffix("deg").unwrap_or(arg).parse::<f64>().ok()?;
+    Some(hue.rem_euclid(360.0))
+}
+
+fn parse_percentage(arg: &str) -> Option<f64> {
+    let value = arg.strip_suffix('%').unwrap_or(arg).parse::<f64>().ok()?;
+    Some(value.clamp(0.0, 100.0))
+}
+
+/// conversion of integers, [Color] and color strings (see [parse_color]) into an ANSI-256 color
+///
+/// > numeric strings wrap like integers (see [wrap])
+///
+/// # Panics
+///
+/// on strings which are not colors (i.e.: `"#ff880"`, `"orang"`) and negative integers
+pub trait IntoAnsi {
+    fn into_ansi(self) -> u8;
+}
+
+macro_rules! impl_into_ansi_for_integer {
+    ($($int:ty),*) => {
+        $(impl IntoAnsi for $int {
+            fn into_ansi(self) -> u8 {
+                match usize::try_from(self) {
+                    Ok(color) => wrap(color),
+                    Err(_) => panic!("invalid color: {self}"),
+                }
+            }
+        })*
+    };
+}
+impl_into_ansi_for_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+impl IntoAnsi for Color {
+    fn into_ansi(self) -> u8 {
+        self.ansi()
+    }
+}
+impl IntoAnsi for &str {
+    fn into_ansi(self) -> u8 {
+        if let Ok(color) = self.trim().parse::<usize>() {
+            return wrap(color);
+        }
+        match parse_color(self) {
+            Some(color) => color.ansi(),
+            None => panic!("invalid color: {self:#?}"),
+        }
+    }
+}
+impl IntoAnsi for String {
+    fn into_ansi(self) -> u8 {
+        self.as_str().into_ansi()
+    }
+}
+impl IntoAnsi for &String {
+    fn into_ansi(self) -> u8 {
+        self.as_str().into_ansi()
+    }
+}
+
+/// fails compilation (when called in a `const` block) unless the
+/// given `stringify!`-ed literal is an integer or a color string
+/// accepted by [IntoAnsi], so that macros taking a `$color:literal`
+/// reject format strings and typos (i.e.: `warn!("hello {}", 5)`)
+#[doc(hidden)]
+pub const fn check_color_literal(literal: &str) {
+    if !is_color_literal(literal.as_bytes()) {
+        panic!(
+            "invalid color literal: expected an integer or a color string (i.e.: `220`, `\"orange\"`, `\"#ff8800\"`) before the message"
+        );
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Function {
+    Ansi,
+    Rgb,
+    Hsl,
+}
+
+const fn is_color_literal(literal: &[u8]) -> bool {
+    match literal {
+        [b'0'..=b'9', ..] => true,
+        [b'"', text @ .., b'"'] => is_color_text(text.trim_ascii()),
+        [b'r', raw @ ..] => {
+            let mut raw = raw;
+            while let [b'#', inner @ .., b'#'] = raw {
+                raw = inner;
+            }
+            match raw {
+                [b'"', text @ .., b'"'] => is_color_text(text.trim_ascii()),
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+const fn is_color_text(text: &[u8]) -> bool {
+    if let [b'#', hex @ ..] = text {
+        return (hex.len() == 3 || hex.len() == 6) && all_hex_digits(hex);
+    }
+    if parse_index(text, usize::MAX).is_some() {
+        return true;
+    }
+    let functions = [
+        ("ansi", Function::Ansi),
+        ("rgba", Function::Rgb),
+        ("rgb", Function::Rgb),
+        ("hsla", Function::Hsl),
+        ("hsl", Function::Hsl),
+    ];
+    let mut index = 0;
+    while index < functions.len() {
+        let (name, function) = functions[index];
+        if let Some(rest) = strip_prefix_ignore_case(text, name.as_bytes())
+            && let [b'(', args @ .., b')'] = rest.trim_ascii()
+        {
+            return are_color_args(args, function);
+        }
+        index += 1;
+    }
+    let mut index = 0;
+    while index < CSS_COLORS.len() {
+        if is_color_name(CSS_COLORS[index].0.as_bytes(), text) {
+            return true;
+        }
+        index += 1;
+    }
+    false
+}
+
+const fn are_color_args(args: &[u8], function: Function) -> bool {
+    let mut rest = args;
+    let mut count = 0;
+    loop {
+        while let [b',' | b'/' | b' ' | b'\t', tail @ ..] = rest {
+            rest = tail;
+        }
+        if rest.is_empty() {
+            break;
+        }
+        let mut end = 0;
+        while end < rest.len() && !matches!(rest[end], b',' | b'/' | b' ' | b'\t') {
+            end += 1;
+        }
+        let (arg, tail) = rest.split_at(end);
+        if !is_color_arg(arg, function, count) {
+            return false;
+        }
+        count += 1;
+        rest = tail;
+    }
+    match function {
+        Function::Ansi => count == 1,
+        Function::Rgb | Function::Hsl => count == 3 || count == 4,
+    }
+}
+
+const fn is_color_arg(arg: &[u8], function: Function, index: usize) -> bool {
+    match (function, index) {
+        (Function::Ansi, _) => parse_index(arg, u8::MAX as usize).is_some(),
+        (_, 3) => true,
+        (Function::Hsl, 0) => match arg {
+            [number @ .., b'd' | b'D', b'e' | b'E', b'g' | b'G'] => is_decimal(number),
+            _ => is_decimal(arg),
+        },
+        _ => match arg {
+            [number @ .., b'%'] => is_decimal(number),
+            _ => is_decimal(arg),
+        },
+    }
+}
+
+/// parses the given ASCII digits as an integer no greater than `max`
+const fn parse_index(digits: &[u8], max: usize) -> Option<usize> {
+    if digits.is_empty() {
+        return None;
+    }
+    let mut value: usize = 0;
+    let mut index = 0;
+    while index < digits.len() {
+        if !digits[index].is_ascii_digit() {
+            return None;
+        }
+        value = match value.checked_mul(10) {
+            Some(value) => match value.checked_add((digits[index] - b'0') as usize) {
+                Some(value) => value,
+                None => return None,
+            },
+            None => return None,
+        };
+        if value > max {
+            return None;
+        }
+        index += 1;
+    }
+    Some(value)
+}
+
+const fn is_decimal(number: &[u8]) -> bool {
+    let number = match number {
+        [b'-' | b'+', number @ ..] => number,
+        _ => number,
+    };
+    let mut digits = 0;
+    let mut dots = 0;
+    let mut index = 0;
+    while index < number.len() {
+        match number[index] {
+            b'0'..=b'9' => digits += 1,
+            b'.' => dots += 1,
+            _ => return false,
+        }
+        index += 1;
+    }
+    digits > 0 && dots <= 1
+}
+
+const fn all_hex_digits(hex: &[u8]) -> bool {
+    let mut index = 0;
+    while index < hex.len() {
+        if !hex[index].is_ascii_hexdigit() {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// compares a CSS color name with the given text ignoring case, spaces, `_` and `-`
+const fn is_color_name(name: &[u8], text: &[u8]) -> bool {
+    let mut position = 0;
+    let mut index = 0;
+    while index < text.len() {
+        let byte = text[index];
+        index += 1;
+        if matches!(byte, b' ' | b'_' | b'-') {
+            continue;
+        }
+        if position >= name.len() || byte.to_ascii_lowercase() != name[position] {
+            return false;
+        }
+        position += 1;
+    }
+    position == name.len()
+}
+
+const fn strip_prefix_ignore_case<'a>(text: &'a [u8], prefix: &[u8]) -> Option<&'a [u8]> {
+    if text.len() < prefix.len() {
+        return None;
+    }
+    let (head, rest) = text.split_at(prefix.len());
+    if head.eq_ignore_ascii_case(prefix) {
+        Some(rest)
+    } else {
+        None
+    }
+}
+
+/// CSS color names and their `[red, green, blue]` triples
+pub const CSS_COLORS: [(&str, [u8; 3]); 148] = [
+    ("black", [0, 0, 0]),
+    ("white", [255, 255, 255]),
+    ("red", [255, 0, 0]),
+    ("lime", [0, 255, 0]),
+    ("blue", [0, 0, 255]),
+    ("yellow", [255, 255, 0]),
+    ("cyan", [0, 255, 255]),
+    ("magenta", [255, 0, 255]),
+    ("gray", [128, 128, 128]),
+    ("silver", [192, 192, 192]),
+    ("maroon", [128, 0, 0]),
+    ("olive", [128, 128, 0]),
+    ("green", [0, 128, 0]),
+    ("purple", [128, 0, 128]),
+    ("teal", [0, 128, 128]),
+    ("navy", [0, 0, 128]),
+    ("orange", [255, 165, 0]),
+    ("aliceblue", [240, 248, 255]),
+    ("antiquewhite", [250, 235, 215]),
+    ("aqua", [0, 255, 255]),
+    ("aquamarine", [127, 255, 212]),
+    ("azure", [240, 255, 255]),
+    ("beige", [245, 245, 220]),
+    ("bisque", [255, 228, 196]),
+    ("blanchedalmond", [255, 235, 205]),
+    ("blueviolet", [138, 43, 226]),
+    ("brown", [165, 42, 42]),
+    ("burlywood", [222, 184, 135]),
+    ("cadetblue", [95, 158, 160]),
+    ("chartreuse", [127, 255, 0]),
+    ("chocolate", [210, 105, 30]),
+    ("coral", [255, 127, 80]),
+    ("cornflowerblue", [100, 149, 237]),
+    ("cornsilk", [255, 248, 220]),
+    ("crimson", [220, 20, 60]),
+    ("darkblue", [0, 0, 139]),
+    ("darkcyan", [0, 139, 139]),
+    ("darkgoldenrod", [184, 134, 11]),
+    ("darkgray", [169, 169, 169]),
+    ("darkgreen", [0, 100, 0]),
+    ("darkgrey", [169, 169, 169]),
+    ("darkkhaki", [189, 183, 107]),
+    ("darkmagenta", [139, 0, 139]),
+    ("darkolivegreen", [85, 107, 47]),
+    ("darkorange", [255, 140, 0]),
+    ("darkorchid", [153, 50, 204]),
+    ("darkred", [139, 0, 0]),
+    ("darksalmon", [233, 150, 122]),
+    ("darkseagreen", [143, 188, 143]),
+    ("darkslateblue", [72, 61, 139]),
+    ("darkslategray", [47, 79, 79]),
+    ("darkslategrey", [47, 79, 79]),
+    ("darkturquoise", [0, 206, 209]),
+    ("darkviolet", [148, 0, 211]),
+    ("deeppink", [255, 20, 147]),
+    ("deepskyblue", [0, 191, 255]),
+    ("dimgray", [105, 105, 105]),
+    ("dimgrey", [105, 105, 105]),
+    ("dodgerblue", [30, 144, 255]),
+    ("firebrick", [178, 34, 34]),
+    ("floralwhite", [255, 250, 240]),
+    ("forestgreen", [34, 139, 34]),
+    ("fuchsia", [255, 0, 255]),
+    ("gainsboro", [220, 220, 220]),
+    ("ghostwhite", [248, 248, 255]),
+    ("gold", [255, 215, 0]),
+    ("goldenrod", [218, 165, 32]),
+    ("grey", [128, 128, 128]),
+    ("greenyellow", [173, 255, 47]),
+    ("honeydew", [240, 255, 240]),
+    ("hotpink", [255, 105, 180]),
+    ("indianred", [205, 92, 92]),
+    ("indigo", [75, 0, 130]),
+    ("ivory", [255, 255, 240]),
+    ("khaki", [240, 230, 140]),
+    ("lavender", [230, 230, 250]),
+    ("lavenderblush", [255, 240, 245]),
+    ("lawngreen", [124, 252, 0]),
+    ("lemonchiffon", [255, 250, 205]),
+    ("lightblue", [173, 216, 230]),
+    ("lightcoral", [240, 128, 128]),
+    ("lightcyan", [224, 255, 255]),
+    ("lightgoldenrodyellow", [250, 250, 210]),
+    ("lightgray", [211, 211, 211]),
+    ("lightgreen", [144, 238, 144]),
+    ("lightgrey", [211, 211, 211]),
+    ("lightpink", [255, 182, 193]),
+    ("lightsalmon", [255, 160, 122]),
+    ("lightseagreen", [32, 178, 170]),
+    ("lightskyblue", [135, 206, 250]),
+    ("lightslategray", [119, 136, 153]),
+    ("lightslategrey", [119, 136, 153]),
+    ("lightsteelblue", [176, 196, 222]),
+    ("lightyellow", [255, 255, 224]),
+    ("limegreen", [50, 205, 50]),
+    ("linen", [250, 240, 230]),
+    ("mediumaquamarine", [102, 205, 170]),
+    ("mediumblue", [0, 0, 205]),
+    ("mediumorchid", [186, 85, 211]),
+    ("mediumpurple", [147, 112, 219]),
+    ("mediumseagreen", [60, 179, 113]),
+    ("mediumslateblue", [123, 104, 238]),
+    ("mediumspringgreen", [0, 250, 154]),
+    ("mediumturquoise", [72, 209, 204]),
+    ("mediumvioletred", [199, 21, 133]),
+    ("midnightblue", [25, 25, 112]),
+    ("mintcream", [245, 255, 250]),
+    ("mistyrose", [255, 228, 225]),
+    ("moccasin", [255, 228, 181]),
+    ("navajowhite", [255, 222, 173]),
+    ("oldlace", [253, 245, 230]),
+    ("olivedrab", [107, 142, 35]),
+    ("orangered", [255, 69, 0]),
+    ("orchid", [218, 112, 214]),
+    ("palegoldenrod", [238, 232, 170]),
+    ("palegreen", [152, 251, 152]),
+    ("paleturquoise", [175, 238, 238]),
+    ("palevioletred", [219, 112, 147]),
+    ("papayawhip", [255, 239, 213]),
+    ("peachpuff", [255, 218, 185]),
+    ("peru", [205, 133, 63]),
+    ("pink", [255, 192, 203]),
+    ("plum", [221, 160, 221]),
+    ("powderblue", [176, 224, 230]),
+    ("rebeccapurple", [102, 51, 153]),
+    ("rosybrown", [188, 143, 143]),
+    ("royalblue", [65, 105, 225]),
+    ("saddlebrown", [139, 69, 19]),
+    ("salmon", [250, 128, 114]),
+    ("sandybrown", [244, 164, 96]),
+    ("seagreen", [46, 139, 87]),
+    ("seashell", [255, 245, 238]),
+    ("sienna", [160, 82, 45]),
+    ("skyblue", [135, 206, 235]),
+    ("slateblue", [106, 90, 205]),
+    ("slategray", [112, 128, 144]),
+    ("slategrey", [112, 128, 144]),
+    ("snow", [255, 250, 250]),
+    ("springgreen", [0, 255, 127]),
+    ("steelblue", [70, 130, 180]),
+    ("tan", [210, 180, 140]),
+    ("thistle", [216, 191, 216]),
+    ("tomato", [255, 99, 71]),
+    ("turquoise", [64, 224, 208]),
+    ("violet", [238, 130, 238]),
+    ("wheat", [245, 222, 179]),
+    ("whitesmoke", [245, 245, 245]),
+    ("yellowgreen", [154, 205, 50]),
+];
@@ -0,0 +1,270 @@
+//! code frames pointing at labeled byte ranges of a source text, i.e.: the input of a parser
+use crate::color::{auto, from_display, strip_ansi};
+use crate::output::emit;
+use std::fmt::{self, Display};
+use std::ops::Range;
+
+/// color of the line-number gutter
+const GUTTER_COLOR: usize = 240;
+
+/// severity of a [Report], colored as [warn](crate::warn!) and [info](crate::info!)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Severity {
+    /// "error" in red
+    Error,
+    /// "warning" in yellow, as [warn](crate::warn!)
+    Warning,
+    /// "info" in blue, as [info](crate::info!)
+    Info,
+}
+
+impl Severity {
+    /// ANSI-256 color of the severity
+    pub fn color(&self) -> usize {
+        match self {
+            Severity::Error => 160,
+            Severity::Warning => 220,
+            Severity::Info => 74,
+        }
+    }
+
+    /// name of the severity as printed in the header of a [Report]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Info => "info",
+        }
+    }
+}
+
+/// message attached to a byte range of the source of a [Report]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Label {
+    /// byte range of the source the label points at
+    pub span: Range<usize>,
+    /// text shown next to the underline of the span
+    pub message: String,
+}
+
+/// builds a code frame of a source text with labeled (and possibly
+/// overlapping or multi-line) byte ranges, i.e.:
+///
+/// ```
+/// use debug_et_diagnostics::Report;
+///
+/// let report = Report::error("unexpected token")
+///     .name("input.dsl")
+///     .source("let x = = 3;")
+///     .label(4..5, "binding starts here")
+///     .label(8..9, "expected an expression");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Report {
+    severity: Severity,
+    message: String,
+    name: Option<String>,
+    source: String,
+    labels: Vec<Label>,
+}
+
+/// a label of a [Report] resolved to the lines and columns it covers
+struct Resolved<'a> {
+    label: &'a Label,
+    color: usize,
+    start: (usize, usize),
+    end: (usize, usize),
+}
+
+impl Report {
+    /// a report of the given severity and message without source or labels
+    pub fn new<T: Display>(severity: Severity, message: T) -> Report {
+        Report {
+            severity,
+            message: message.to_string(),
+            name: None,
+            source: String::new(),
+            labels: Vec::new(),
+        }
+    }
+
+    /// shorthand of [Report::new] with [Severity::Error]
+    pub fn error<T: Display>(message: T) -> Report {
+        Report::new(Severity::Error, message)
+    }
+
+    /// shorthand of [Report::new] with [Severity::Warning]
+    pub fn warning<T: Display>(message: T) -> Report {
+        Report::new(Severity::Warning, message)
+    }
+
+    /// shorthand of [Report::new] with [Severity::Info]
+    pub fn info<T: Display>(message: T) -> Report {
+        Report::new(Severity::Info, message)
+    }
+
+    /// name of the source (i.e.: a filename) shown along with the line and column of the first label
+    pub fn name<T: Display>(mut self, name: T) -> Report {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// the text the spans of the labels point into
+    pub fn source<T: Display>(mut self, source: T) -> Report {
+        self.source = source.to_string();
+        self
+    }
+
+    /// labels the given byte range of the source, clamped to the source and char boundaries
+    pub fn label<T: Display>(mut self, span: Range<usize>, message: T) -> Report {
+        self.labels.push(Label {
+            span,
+            message: message.to_string(),
+        });
+        self
+    }
+
+    /// severity given to [Report::new]
+    pub fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// message given to [Report::new]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// labels added via [Report::label], in order
+    pub fn labels(&self) -> &[Label] {
+        &self.labels
+    }
+
+    /// 0-based line and column (in chars) of the given byte offset
+    fn position(&self, offset: usize) -> (usize, usize) {
+        let mut offset = offset.min(self.source.len());
+        while !self.source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let before = &self.source[..offset];
+        let line = before.matches('\n').count();
+        let start = before
+            .rfind('\n')
+            .map(|index| index + 1)
+            .unwrap_or_default();
+        (line, before[start..].chars().count())
+    }
+
+    fn resolve(&self) -> Vec<Resolved<'_>> {
+        self.labels
+            .iter()
+            .map(|label| {
+                let start = self.position(label.span.start);
+                let mut end = self.position(label.span.end.max(label.span.start));
+                // spans including a line break end on the line it breaks
+                if end.1 == 0 && end.0 > start.0 {
+                    end = self.position(label.span.end - 1);
+                }
+                Resolved {
+                    label,
+                    color: from_display(&label.message).into(),
+                    start,
+                    end,
+                }
+            })
+            .collect()
+    }
+
+    /// renders the code frame, without colors unless [color_enabled](crate::color::color_enabled)
+    pub fn render(&self) -> String {
+        self.render_with_color(crate::color::color_enabled())
+    }
+
+    /// renders the code frame with or without colors regardless of [set_color](crate::color::set_color)
+    pub fn render_with_color(&self, colors: bool) -> String {
+        let color = self.severity.color();
+        let mut output = vec![format!(
+            "{}{} {}",
+            crate::color::fore(self.severity.name(), color),
+            crate::color::fore(":", color),
+            self.message
+        )];
+        let labels = self.resolve();
+        let lines = self.source.split('\n').collect::<Vec<&str>>();
+        let mut numbers = labels
+            .iter()
+            .flat_map(|resolved| resolved.start.0..=resolved.end.0)
+            .collect::<Vec<usize>>();
+        numbers.sort_unstable();
+        numbers.dedup();
+        let width = numbers
+            .last()
+            .map(|number| (number + 1).to_string().len())
+            .unwrap_or(1);
+        let gutter =
+            |number: String| crate::color::fore(format!("{number:>width$} │"), GUTTER_COLOR);
+        let empty = gutter(String::new());
+
+        if let Some(first) = labels.first() {
+            let (line, column) = first.start;
+            let location = format!("{}:{}", line + 1, column + 1);
+            output.push(format!(
+                "{} {}",
+                crate::color::fore(format!("{:>width$} ┌─", ""), GUTTER_COLOR),
+                match &self.name {
+                    Some(name) => format!("{name}:{location}"),
+                    None => location,
+                }
+            ));
+            output.push(empty.clone());
+        }
+        let mut previous: Option<usize> = None;
+        for number in numbers {
+            if previous.is_some_and(|previous| number > previous + 1) {
+                output.push(crate::color::fore(
+                    format!("{:>width$} ┆", ""),
+                    GUTTER_COLOR,
+                ));
+            }
+            previous = Some(number);
+            let text = lines.get(number).copied().unwrap_or_default();
+            let length = text.chars().count();
+            output.push(format!("{} {}", gutter((number + 1).to_string()), text));
+            for resolved in &labels {
+                let (start, end) = (resolved.start, resolved.end);
+                if number < start.0 || number > end.0 {
+                    continue;
+                }
+                let from = if number == start.0 { start.1 } else { 0 };
+                let to = if number == end.0 { end.1 } else { length };
+                let padding = text
+                    .chars()
+                    .chain(std::iter::repeat(' '))
+                    .take(from)
+                    .map(|c| if c == '\t' { '\t' } else { ' ' })
+                    .collect::<String>();
+                let underline =
+                    crate::color::fore("^".repeat(to.saturating_sub(from).max(1)), resolved.color);
+                // multi-line spans carry their message on their last line
+                let message = if number == end.0 {
+                    format!(" {}", auto(&resolved.label.message))
+                } else {
+                    String::new()
+                };
+                output.push(format!("{empty} {padding}{underline}{message}"));
+            }
+        }
+        let output = output.join("\n");
+        if colors { output } else { strip_ansi(output) }
+    }
+
+    /// prints the report to stderr, see [emit]
+    pub fn emit(&self) {
+        emit(self.render());
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render())
+    }
+}
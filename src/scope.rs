@@ -0,0 +1,120 @@
+//! RAII guards which colorfully print the entry and exit of a scope
+use crate::color::{auto, fore};
+use crate::location::{format_begin, format_end};
+use crate::output::{emit_branch, emit_branch_at};
+use crate::timing::format_duration;
+use std::cell::Cell;
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+thread_local! {
+    static DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// call depth of the current thread, driven by [Scope] guards or [enter]/[exit]
+pub fn depth() -> usize {
+    DEPTH.with(Cell::get)
+}
+
+/// prints "in function" at the given location (i.e.: [location](crate::location!))
+/// and increments the call [depth], see [enter](crate::enter!)
+pub fn enter<T: Display>(location: T) {
+    let depth = DEPTH.with(|depth| depth.replace(depth.get() + 1));
+    emit_branch(format_begin(location), depth, false);
+}
+
+/// decrements the call [depth] and prints "from function" at the given
+/// location (i.e.: [location](crate::location!)), see [exit](crate::exit!)
+pub fn exit<T: Display>(location: T) {
+    let depth = DEPTH.with(|depth| {
+        depth.set(depth.get().saturating_sub(1));
+        depth.get()
+    });
+    emit_branch(format_end(location), depth, true);
+}
+
+/// prints "in function" when created and "from function" when dropped,
+/// see [scope](crate::scope!)
+#[derive(Debug)]
+pub struct Scope {
+    location: String,
+    start: Instant,
+    depth: usize,
+    detached: bool,
+}
+
+impl Scope {
+    /// prints the entry of the scope at the given location (i.e.: [location](crate::location!))
+    #[must_use = "the scope ends as soon as the guard is dropped"]
+    pub fn new<T: Display>(location: T) -> Scope {
+        Scope::with_lines(location, &[])
+    }
+
+    /// prints the entry of the scope followed by the given lines (i.e.: the
+    /// arguments of a `#[trace]` function) as a single block
+    #[must_use = "the scope ends as soon as the guard is dropped"]
+    pub fn with_lines<T: Display>(location: T, lines: &[String]) -> Scope {
+        let depth = DEPTH.with(|depth| depth.replace(depth.get() + 1));
+        Scope::open(location.to_string(), lines, depth, false)
+    }
+
+    /// prints the entry of the scope at the current [depth] without changing
+    /// it, i.e.: within futures which may be suspended and resumed on other
+    /// threads, interleaved with other tasks, while the depth is thread-local
+    #[must_use = "the scope ends as soon as the guard is dropped"]
+    pub fn detached<T: Display>(location: T) -> Scope {
+        Scope::detached_with_lines(location, &[])
+    }
+
+    /// [Scope::detached] followed by the given lines, see [Scope::with_lines]
+    #[must_use = "the scope ends as soon as the guard is dropped"]
+    pub fn detached_with_lines<T: Display>(location: T, lines: &[String]) -> Scope {
+        Scope::open(location.to_string(), lines, depth(), true)
+    }
+
+    fn open(location: String, lines: &[String], depth: usize, detached: bool) -> Scope {
+        let mut block = vec![format_begin(&location)];
+        block.extend_from_slice(lines);
+        let lines_depth = if detached { depth } else { depth + 1 };
+        emit_branch_at(block.join("\n"), depth, false, lines_depth);
+        Scope {
+            location,
+            start: Instant::now(),
+            depth,
+            detached,
+        }
+    }
+
+    /// location given to [Scope::new]
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    /// call [depth] when the guard was created
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// time elapsed since the guard was created
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        if !self.detached {
+            DEPTH.with(|depth| depth.set(self.depth));
+        }
+        let mut parts = vec![
+            auto("from function"),
+            self.location.clone(),
+            fore("after", 7),
+            format_duration(self.elapsed()),
+        ];
+        if std::thread::panicking() {
+            parts.push(fore("while panicking", 160));
+        }
+        emit_branch(crate::tag!(parts.join(" ")), self.depth, true);
+    }
+}
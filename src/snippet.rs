@@ -0,0 +1,136 @@
+//! source snippets of the call site of [step](crate::step!), [warn](crate::warn!) and friends
+use crate::color::fore;
+use crate::output::emit;
+use std::path::{Path, PathBuf};
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// number of lines of context shown before and after the invoking line
+pub const CONTEXT: usize = 2;
+/// color of the line-number gutter
+const GUTTER_COLOR: usize = 240;
+/// color of the arrow and caret marking the invoking line
+const MARKER_COLOR: usize = 220;
+
+static SOURCE_SNIPPETS: AtomicBool = AtomicBool::new(false);
+static SOURCE_ROOT: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// shows the source lines around the call site of [step](crate::step!),
+/// [warn](crate::warn!), [info](crate::info!) and [admonition](crate::admonition!)
+pub fn set_source_snippets(enabled: bool) {
+    SOURCE_SNIPPETS.store(enabled, Ordering::Relaxed);
+}
+
+/// whether source snippets are shown, see [set_source_snippets]
+pub fn source_snippets() -> bool {
+    SOURCE_SNIPPETS.load(Ordering::Relaxed)
+}
+
+/// resolves the relative paths of `file!()` against the given root rather
+/// than the `CARGO_MANIFEST_DIR` of the calling crate (and its ancestors)
+pub fn set_source_root(root: Option<PathBuf>) {
+    *SOURCE_ROOT
+        .write()
+        .unwrap_or_else(|error| error.into_inner()) = root;
+}
+
+/// the root set via [set_source_root]
+pub fn source_root() -> Option<PathBuf> {
+    SOURCE_ROOT
+        .read()
+        .unwrap_or_else(|error| error.into_inner())
+        .clone()
+}
+
+/// resolves the given `file!()` path against the [source_root] or else the
+/// given manifest dir and its ancestors (`file!()` is relative to the
+/// workspace root within workspaces)
+pub fn resolve_source(manifest_dir: &str, file: &str) -> Option<PathBuf> {
+    let path = Path::new(file);
+    if path.is_absolute() {
+        return path.is_file().then(|| path.to_path_buf());
+    }
+    if let Some(root) = source_root() {
+        let path = root.join(path);
+        return path.is_file().then_some(path);
+    }
+    Path::new(manifest_dir)
+        .ancestors()
+        .map(|root| root.join(path))
+        .find(|path| path.is_file())
+}
+
+/// renders [CONTEXT] lines around the given 1-based line of the given
+/// source with a line-number gutter, an arrow at the given line and a caret
+/// at the given 1-based column
+pub fn render_snippet(source: &str, line: usize, column: usize) -> Option<String> {
+    let lines = source.lines().collect::<Vec<&str>>();
+    let index = line.checked_sub(1).filter(|index| *index < lines.len())?;
+    let first = index.saturating_sub(CONTEXT);
+    let last = (index + CONTEXT).min(lines.len() - 1);
+    let width = (last + 1).to_string().len();
+    let gutter = |number: String| fore(format!("{number:>width$} │"), GUTTER_COLOR);
+    let mut snippet = Vec::<String>::new();
+    for (offset, text) in lines[first..=last].iter().enumerate() {
+        let number = first + offset + 1;
+        if number == line {
+            snippet.push(format!(
+                "{} {} {}",
+                fore("→", MARKER_COLOR),
+                gutter(number.to_string()),
+                text
+            ));
+            // keeps tabs so that the caret lines up with the column
+            let padding = text
+                .chars()
+                .take(column.saturating_sub(1))
+                .map(|c| if c == '\t' { '\t' } else { ' ' })
+                .collect::<String>();
+            snippet.push(format!(
+                "  {} {}{}",
+                gutter(String::new()),
+                padding,
+                fore("^", MARKER_COLOR)
+            ));
+        } else {
+            snippet.push(format!("  {} {}", gutter(number.to_string()), text));
+        }
+    }
+    Some(snippet.join("\n"))
+}
+
+/// reads the source of the given `file!()` (see [resolve_source]) and
+/// renders the snippet around the given line and column, see [render_snippet]
+pub fn format_snippet(manifest_dir: &str, file: &str, line: u32, column: u32) -> Option<String> {
+    let source = std::fs::read_to_string(resolve_source(manifest_dir, file)?).ok()?;
+    render_snippet(&source, line as usize, column as usize)
+}
+
+/// the given block (i.e.: a banner) followed by the snippet of the given call
+/// site if [source_snippets] are enabled and the source file can be read, so
+/// that both are printed at once
+pub fn append_snippet(
+    block: String,
+    manifest_dir: &str,
+    file: &str,
+    line: u32,
+    column: u32,
+) -> String {
+    match source_snippets()
+        .then(|| format_snippet(manifest_dir, file, line, column))
+        .flatten()
+    {
+        Some(snippet) => format!("{block}\n{snippet}"),
+        None => block,
+    }
+}
+
+/// prints the snippet of the given call site if [source_snippets] are
+/// enabled and the source file can be read, see [snippet](crate::snippet!)
+pub fn emit_snippet(manifest_dir: &str, file: &str, line: u32, column: u32) {
+    if source_snippets()
+        && let Some(snippet) = format_snippet(manifest_dir, file, line, column)
+    {
+        emit(snippet);
+    }
+}
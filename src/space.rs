@@ -0,0 +1,262 @@
+//! pure-rust conversions between RGB and the HSL, HSV, OKLab and CIELAB color spaces
+//!
+//! > RGB triples are `[red, green, blue]` in `0..=255`, hues are in
+//! > degrees, saturation, lightness and value are percentages.
+
+/// D65 reference white in CIE XYZ
+const D65: [f64; 3] = [0.95047, 1.0, 1.08883];
+
+/// converts an sRGB channel to linear light
+pub(crate) fn to_linear(channel: u8) -> f64 {
+    let c = channel as f64 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// converts a channel in linear light back to sRGB
+pub(crate) fn from_linear(channel: f64) -> u8 {
+    let c = channel.clamp(0.0, 1.0);
+    let c = if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (c * 255.0).round() as u8
+}
+
+fn to_channel(unit: f64) -> u8 {
+    unit.clamp(0.0, 255.0).round() as u8
+}
+
+/// converts a `[red, green, blue]` triple to `[hue, saturation, lightness]`
+pub fn rgb_to_hsl(rgb: [u8; 3]) -> [f64; 3] {
+    rgb_units_to_hsl(rgb.map(f64::from))
+}
+
+/// converts `[hue, saturation, lightness]` to a `[red, green, blue]` triple
+pub fn hsl_to_rgb(hsl: [f64; 3]) -> [u8; 3] {
+    hsl_to_rgb_units(hsl).map(to_channel)
+}
+
+fn rgb_units_to_hsl(rgb: [f64; 3]) -> [f64; 3] {
+    let [red, green, blue] = rgb.map(|c| c.clamp(0.0, 255.0) / 255.0);
+    let max = red.max(green).max(blue);
+    let min = red.min(green).min(blue);
+    let lightness = (max + min) / 2.0;
+    if max == min {
+        return [0.0, 0.0, lightness * 100.0];
+    }
+    let delta = max - min;
+    let saturation = if lightness > 0.5 {
+        delta / (2.0 - max - min)
+    } else {
+        delta / (max + min)
+    };
+    let hue = if max == red {
+        (green - blue) / delta + if green < blue { 6.0 } else { 0.0 }
+    } else if max == green {
+        (blue - red) / delta + 2.0
+    } else {
+        (red - green) / delta + 4.0
+    };
+    [hue * 60.0, saturation * 100.0, lightness * 100.0]
+}
+
+fn hsl_to_rgb_units(hsl: [f64; 3]) -> [f64; 3] {
+    let hue = hsl[0].rem_euclid(360.0) / 360.0;
+    let saturation = hsl[1].clamp(0.0, 100.0) / 100.0;
+    let lightness = hsl[2].clamp(0.0, 100.0) / 100.0;
+    if saturation == 0.0 {
+        return [lightness * 255.0; 3];
+    }
+    let temp1 = if lightness < 0.5 {
+        lightness * (1.0 + saturation)
+    } else {
+        lightness + saturation - lightness * saturation
+    };
+    let temp2 = 2.0 * lightness - temp1;
+    [hue + 1.0 / 3.0, hue, hue - 1.0 / 3.0].map(|unit| {
+        let unit = unit.rem_euclid(1.0);
+        let channel = if 6.0 * unit < 1.0 {
+            temp2 + (temp1 - temp2) * 6.0 * unit
+        } else if 2.0 * unit < 1.0 {
+            temp1
+        } else if 3.0 * unit < 2.0 {
+            temp2 + (temp1 - temp2) * (2.0 / 3.0 - unit) * 6.0
+        } else {
+            temp2
+        };
+        channel * 255.0
+    })
+}
+
+/// converts a `[red, green, blue]` triple to `[hue, saturation, value]`
+pub fn rgb_to_hsv(rgb: [u8; 3]) -> [f64; 3] {
+    let [red, green, blue] = rgb.map(|c| c as f64 / 255.0);
+    let max = red.max(green).max(blue);
+    let min = red.min(green).min(blue);
+    let delta = max - min;
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == red {
+        60.0 * ((green - blue) / delta).rem_euclid(6.0)
+    } else if max == green {
+        60.0 * ((blue - red) / delta + 2.0)
+    } else {
+        60.0 * ((red - green) / delta + 4.0)
+    };
+    let saturation = if max == 0.0 { 0.0 } else { delta / max };
+    [hue, saturation * 100.0, max * 100.0]
+}
+
+/// converts `[hue, saturation, value]` to a `[red, green, blue]` triple
+pub fn hsv_to_rgb(hsv: [f64; 3]) -> [u8; 3] {
+    let hue = hsv[0].rem_euclid(360.0);
+    let saturation = hsv[1].clamp(0.0, 100.0) / 100.0;
+    let value = hsv[2].clamp(0.0, 100.0) / 100.0;
+    let chroma = value * saturation;
+    let x = chroma * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+    let (r, g, b) = match (hue / 60.0) as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = value - chroma;
+    [r, g, b].map(|c| to_channel((c + m) * 255.0))
+}
+
+/// converts a `[red, green, blue]` triple to OKLab `[lightness, a, b]`
+pub fn rgb_to_oklab(rgb: [u8; 3]) -> [f64; 3] {
+    let [r, g, b] = rgb.map(to_linear);
+    let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+    let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+    let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+    [
+        0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+        1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+        0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+    ]
+}
+
+/// converts OKLab `[lightness, a, b]` to a `[red, green, blue]` triple
+pub fn oklab_to_rgb(lab: [f64; 3]) -> [u8; 3] {
+    oklab_to_linear(lab).map(from_linear)
+}
+
+/// converts OKLab `[lightness, a, b]` to unclamped linear `[red, green, blue]` units
+fn oklab_to_linear(lab: [f64; 3]) -> [f64; 3] {
+    let [lightness, a, b] = lab;
+    let l = (lightness + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+    let m = (lightness - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+    let s = (lightness - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+    [
+        4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+        -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+        -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+    ]
+}
+
+/// converts OKLab `[lightness, a, b]` to OKLCh `[lightness, chroma, hue]`
+pub fn oklab_to_oklch(lab: [f64; 3]) -> [f64; 3] {
+    let [lightness, a, b] = lab;
+    [
+        lightness,
+        a.hypot(b),
+        b.atan2(a).to_degrees().rem_euclid(360.0),
+    ]
+}
+
+/// converts OKLCh `[lightness, chroma, hue]` to OKLab `[lightness, a, b]`
+pub fn oklch_to_oklab(lch: [f64; 3]) -> [f64; 3] {
+    let [lightness, chroma, hue] = lch;
+    let hue = hue.to_radians();
+    [lightness, chroma * hue.cos(), chroma * hue.sin()]
+}
+
+/// converts OKLCh `[lightness, chroma, hue]` to the `[red, green, blue]`
+/// triple of the same lightness and hue, reducing the chroma until it fits
+/// in the sRGB gamut rather than clipping each channel
+pub fn oklch_to_rgb(lch: [f64; 3]) -> [u8; 3] {
+    let [lightness, chroma, hue] = lch;
+    let lightness = lightness.clamp(0.0, 1.0);
+    let in_gamut = |chroma: f64| {
+        oklab_to_linear(oklch_to_oklab([lightness, chroma, hue]))
+            .iter()
+            .all(|unit| (-0.0001..=1.0001).contains(unit))
+    };
+    let (mut low, mut high) = (0.0, chroma.max(0.0));
+    if !in_gamut(high) {
+        for _ in 0..24 {
+            let middle = (low + high) / 2.0;
+            if in_gamut(middle) {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+        high = low;
+    }
+    oklab_to_rgb(oklch_to_oklab([lightness, high, hue]))
+}
+
+fn lab_f(t: f64) -> f64 {
+    if t > (6.0f64 / 29.0).powi(3) {
+        t.cbrt()
+    } else {
+        t / (3.0 * (6.0f64 / 29.0).powi(2)) + 4.0 / 29.0
+    }
+}
+
+fn lab_f_inverse(t: f64) -> f64 {
+    if t > 6.0 / 29.0 {
+        t.powi(3)
+    } else {
+        3.0 * (6.0f64 / 29.0).powi(2) * (t - 4.0 / 29.0)
+    }
+}
+
+/// converts a `[red, green, blue]` triple to CIELAB `[lightness, a, b]` (D65)
+pub fn rgb_to_lab(rgb: [u8; 3]) -> [f64; 3] {
+    let [r, g, b] = rgb.map(to_linear);
+    let x = 0.4124564 * r + 0.3575761 * g + 0.1804375 * b;
+    let y = 0.2126729 * r + 0.7151522 * g + 0.0721750 * b;
+    let z = 0.0193339 * r + 0.1191920 * g + 0.9503041 * b;
+    let [fx, fy, fz] = [x / D65[0], y / D65[1], z / D65[2]].map(lab_f);
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+/// converts CIELAB `[lightness, a, b]` (D65) to a `[red, green, blue]` triple
+pub fn lab_to_rgb(lab: [f64; 3]) -> [u8; 3] {
+    let fy = (lab[0] + 16.0) / 116.0;
+    let fx = fy + lab[1] / 500.0;
+    let fz = fy - lab[2] / 200.0;
+    let x = lab_f_inverse(fx) * D65[0];
+    let y = lab_f_inverse(fy) * D65[1];
+    let z = lab_f_inverse(fz) * D65[2];
+    [
+        3.2404542 * x - 1.5371385 * y - 0.4985314 * z,
+        -0.9692660 * x + 1.8760108 * y + 0.0415560 * z,
+        0.0556434 * x - 0.2040259 * y + 1.0572252 * z,
+    ]
+    .map(from_linear)
+}
+
+/// perceptual distance (CIE76 ΔE) between two `[red, green, blue]` triples
+pub fn delta_e(a: [u8; 3], b: [u8; 3]) -> f64 {
+    let a = rgb_to_lab(a);
+    let b = rgb_to_lab(b);
+    ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt()
+}
+
+/// perceptual distance between two `[red, green, blue]` triples in OKLab
+pub fn oklab_distance(a: [u8; 3], b: [u8; 3]) -> f64 {
+    let a = rgb_to_oklab(a);
+    let b = rgb_to_oklab(b);
+    ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt()
+}
@@ -0,0 +1,138 @@
+//! extension traits which print values in passing, i.e.: within method chains
+use crate::color::{auto, reset};
+use crate::limits::limits;
+use crate::location::{SourceLocation, format_location};
+use crate::meta::short_type_name;
+use crate::output::{INDENTATION, emit, indent_lines};
+use std::fmt::Debug;
+use std::panic::Location;
+
+/// prints the given lines below a tag with the given name and the caller location
+pub(crate) fn emit_tap(name: &str, location: &Location, lines: String) {
+    emit(reset(
+        [
+            crate::tag!(
+                [
+                    auto(format!("{name} at")),
+                    format_location(None, &SourceLocation::caller(location))
+                ]
+                .join(" ")
+            ),
+            lines,
+        ]
+        .join("\n"),
+    ));
+}
+
+/// prints any [Debug] value in passing
+pub trait Tap: Debug + Sized {
+    /// prints the value via [format_dbg](crate::format_dbg!) along with the
+    /// caller location and returns it unchanged
+    #[track_caller]
+    fn dbg(self) -> Self {
+        let label = short_type_name(std::any::type_name::<Self>());
+        emit_tap(
+            "dbg",
+            Location::caller(),
+            crate::format_dbg!(@labeled label, &self, limits()),
+        );
+        self
+    }
+}
+
+impl<T: Debug> Tap for T {}
+
+/// extension of [Option] which prints values in passing
+pub trait OptionExt<T> {
+    /// prints the value (if any) via [format_dbg](crate::format_dbg!) along
+    /// with the caller location and returns the option unchanged
+    #[track_caller]
+    fn dbg_some(self) -> Self
+    where
+        T: Debug;
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    #[track_caller]
+    fn dbg_some(self) -> Self
+    where
+        T: Debug,
+    {
+        if let Some(value) = &self {
+            emit_tap(
+                "some",
+                Location::caller(),
+                crate::format_dbg!(@labeled "Some", value, limits()),
+            );
+        }
+        self
+    }
+}
+
+/// extension of [Iterator]s which prints items in passing
+pub trait IteratorExt: Iterator + Sized {
+    /// prints each item along with its index via [format_dbg](crate::format_dbg!)
+    /// and, once exhausted, the number of items, see [DbgEach]
+    #[track_caller]
+    fn dbg_each(self) -> DbgEach<Self>
+    where
+        Self::Item: Debug,
+    {
+        DbgEach {
+            iter: self,
+            index: 0,
+            location: Location::caller(),
+            done: false,
+        }
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+/// iterator returned by [IteratorExt::dbg_each]
+#[derive(Debug, Clone)]
+pub struct DbgEach<I> {
+    iter: I,
+    index: usize,
+    location: &'static Location<'static>,
+    done: bool,
+}
+
+impl<I: Iterator> Iterator for DbgEach<I>
+where
+    I::Item: Debug,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        match self.iter.next() {
+            Some(item) => {
+                emit_tap(
+                    "each",
+                    self.location,
+                    crate::format_dbg!(@labeled format!("[{}]", self.index), &item, limits()),
+                );
+                self.index += 1;
+                Some(item)
+            }
+            None => {
+                if !self.done {
+                    self.done = true;
+                    emit_tap(
+                        "each",
+                        self.location,
+                        indent_lines(
+                            format!("{} = {}", auto("count"), auto(self.index)),
+                            &" ".repeat(INDENTATION),
+                        ),
+                    );
+                }
+                None
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
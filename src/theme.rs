@@ -0,0 +1,66 @@
+//! colors of the categories of tokens rendered by [ColorDebug](crate::color_debug::ColorDebug)
+//! and [highlight](crate::highlight::highlight)
+use std::sync::RwLock;
+
+static THEME: RwLock<Theme> = RwLock::new(Theme::DEFAULT);
+
+/// ANSI-256 colors of each category of tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Theme {
+    /// names of fields (i.e.: `name` in `Person { name: "Gabriel" }`)
+    pub field: u8,
+    /// names of types and enum variants
+    pub type_name: u8,
+    /// braces, parentheses, colons and commas
+    pub punctuation: u8,
+    /// values hidden by `#[color_debug(redact)]`
+    pub redacted: u8,
+    /// string literals
+    pub string: u8,
+    /// char literals
+    pub char: u8,
+    /// lifetimes and labels
+    pub lifetime: u8,
+    /// integer and float literals
+    pub number: u8,
+    /// `None`, `Some`, `Ok`, `Err`, `true` and `false`
+    pub keyword: u8,
+    /// other identifiers
+    pub identifier: u8,
+    /// colors brackets by nesting depth via [bracket_color](crate::highlight::bracket_color)
+    /// rather than [Theme::punctuation]
+    pub rainbow_brackets: bool,
+}
+
+impl Theme {
+    /// default theme
+    pub const DEFAULT: Theme = Theme {
+        field: 110,
+        type_name: 179,
+        punctuation: 245,
+        redacted: 160,
+        string: 114,
+        char: 150,
+        lifetime: 180,
+        number: 141,
+        keyword: 75,
+        identifier: 252,
+        rainbow_brackets: true,
+    };
+}
+
+impl Default for Theme {
+    fn default() -> Theme {
+        Theme::DEFAULT
+    }
+}
+
+/// sets the theme used by the colorful renderers
+pub fn set_theme(theme: Theme) {
+    *THEME.write().unwrap_or_else(|error| error.into_inner()) = theme;
+}
+
+/// the theme set via [set_theme] or [Theme::DEFAULT]
+pub fn theme() -> Theme {
+    *THEME.read().unwrap_or_else(|error| error.into_inner())
+}
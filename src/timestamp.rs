@@ -0,0 +1,207 @@
+//! timestamp column of diagnostics: wall-clock time, time since start and
+//! delta since the previous diagnostic
+use crate::color::fore;
+use crate::timing::duration_color;
+use std::sync::{Mutex, OnceLock, RwLock};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// environment variable read when no [Timestamps] are set via [set_timestamps],
+/// i.e.: `DEBUG_ET_DIAGNOSTICS_TIMESTAMPS=wall,delta`, see [parse_timestamps]
+pub const TIMESTAMPS_ENV: &str = "DEBUG_ET_DIAGNOSTICS_TIMESTAMPS";
+/// width of [rfc3339] timestamps, i.e.: `2026-10-19T12:34:56.789Z`
+const WALL_WIDTH: usize = 24;
+/// color of the wall-clock time and the time since start
+const TIMESTAMP_COLOR: usize = 245;
+
+static TIMESTAMPS: RwLock<Option<Timestamps>> = RwLock::new(None);
+static START: OnceLock<Instant> = OnceLock::new();
+static LAST: Mutex<Option<Instant>> = Mutex::new(None);
+
+/// columns of the timestamp printed before each diagnostic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Timestamps {
+    /// UTC wall-clock time in RFC 3339, see [rfc3339]
+    pub wall: bool,
+    /// time since [mark_start] (or else the first diagnostic), see [format_clock]
+    pub since_start: bool,
+    /// time since the previous diagnostic colored by magnitude via
+    /// [duration_color], see [format_clock]
+    pub delta: bool,
+}
+
+impl Timestamps {
+    /// no timestamps
+    pub const NONE: Timestamps = Timestamps {
+        wall: false,
+        since_start: false,
+        delta: false,
+    };
+    /// all timestamps
+    pub const ALL: Timestamps = Timestamps {
+        wall: true,
+        since_start: true,
+        delta: true,
+    };
+}
+
+/// parses a comma-separated list of `wall` (or `rfc3339`), `start` (or
+/// `elapsed`) and `delta`, or `all`; anything else is ignored
+pub fn parse_timestamps(value: &str) -> Timestamps {
+    let mut timestamps = Timestamps::NONE;
+    for name in value.split(',').map(|name| name.trim().to_lowercase()) {
+        match name.as_str() {
+            "wall" | "rfc3339" => timestamps.wall = true,
+            "start" | "elapsed" => timestamps.since_start = true,
+            "delta" => timestamps.delta = true,
+            "all" | "1" | "true" => timestamps = Timestamps::ALL,
+            _ => {}
+        }
+    }
+    timestamps
+}
+
+/// sets the timestamp columns, [None] restores the default read from [TIMESTAMPS_ENV]
+pub fn set_timestamps(timestamps: Option<Timestamps>) {
+    *TIMESTAMPS
+        .write()
+        .unwrap_or_else(|error| error.into_inner()) = timestamps;
+}
+
+/// the timestamps set via [set_timestamps] or else read from [TIMESTAMPS_ENV]
+pub fn timestamps() -> Timestamps {
+    static ENV: OnceLock<Timestamps> = OnceLock::new();
+    TIMESTAMPS
+        .read()
+        .unwrap_or_else(|error| error.into_inner())
+        .unwrap_or_else(|| {
+            *ENV.get_or_init(|| {
+                std::env::var(TIMESTAMPS_ENV)
+                    .map(|value| parse_timestamps(&value))
+                    .unwrap_or_default()
+            })
+        })
+}
+
+/// marks the start which [since_start] measures from, i.e.: first thing in `main`
+pub fn mark_start() {
+    START.get_or_init(Instant::now);
+}
+
+/// time since [mark_start] (or else the first call)
+pub fn since_start() -> Duration {
+    START.get_or_init(Instant::now).elapsed()
+}
+
+/// days since the unix epoch to (year, month, day) of the proleptic
+/// gregorian calendar (i.e.: Howard Hinnant's `civil_from_days`)
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// formats the given time as UTC RFC 3339 with milliseconds, i.e.: `2026-10-19T12:34:56.789Z`
+pub fn rfc3339(time: SystemTime) -> String {
+    let (sign, since_epoch) = match time.duration_since(UNIX_EPOCH) {
+        Ok(duration) => (1, duration),
+        Err(error) => (-1, error.duration()),
+    };
+    let mut seconds = sign * since_epoch.as_secs() as i64;
+    let mut millis = since_epoch.subsec_millis();
+    if sign < 0 && millis > 0 {
+        seconds -= 1;
+        millis = 1_000 - millis;
+    }
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86_400));
+    let time_of_day = seconds.rem_euclid(86_400);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{millis:03}Z",
+        time_of_day / 3_600,
+        time_of_day % 3_600 / 60,
+        time_of_day % 60,
+    )
+}
+
+/// formats the given duration as `HH:MM:SS.mmm`, i.e.: `01:02:03.004`,
+/// whose hours widen past 99 rather than overflowing the column
+pub fn format_clock(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        secs / 3_600,
+        secs % 3_600 / 60,
+        secs % 60,
+        duration.subsec_millis()
+    )
+}
+
+/// time since the previous diagnostic without counting as one, see [since_last_diagnostic]
+fn pending_delta() -> Duration {
+    LAST.lock()
+        .unwrap_or_else(|error| error.into_inner())
+        .map(|last| last.elapsed())
+        .unwrap_or_default()
+}
+
+/// time since the previous call (i.e.: the previous diagnostic), zero on the first call
+pub fn since_last_diagnostic() -> Duration {
+    let now = Instant::now();
+    LAST.lock()
+        .unwrap_or_else(|error| error.into_inner())
+        .replace(now)
+        .map(|last| now.duration_since(last))
+        .unwrap_or_default()
+}
+
+/// number of columns of the timestamp column (including its trailing space)
+/// as rendered by [format_timestamp] at this time
+pub fn timestamp_width() -> usize {
+    let timestamps = timestamps();
+    let clock_width = |duration| format_clock(duration).len();
+    [
+        (timestamps.wall, WALL_WIDTH),
+        (timestamps.since_start, clock_width(since_start())),
+        (timestamps.delta, clock_width(pending_delta()) + 1),
+    ]
+    .iter()
+    .filter(|(enabled, _)| *enabled)
+    .map(|(_, width)| width + 1)
+    .sum()
+}
+
+/// the enabled [timestamps] followed by a space (or empty if none is
+/// enabled), which counts as the previous diagnostic of the next delta
+pub fn format_timestamp() -> String {
+    let timestamps = timestamps();
+    let mut columns = Vec::<String>::new();
+    if timestamps.wall {
+        columns.push(fore(rfc3339(SystemTime::now()), TIMESTAMP_COLOR));
+    }
+    if timestamps.since_start {
+        columns.push(fore(format_clock(since_start()), TIMESTAMP_COLOR));
+    }
+    if timestamps.delta {
+        let delta = since_last_diagnostic();
+        columns.push(fore(
+            format!("+{}", format_clock(delta)),
+            duration_color(delta).into(),
+        ));
+    }
+    if columns.is_empty() {
+        String::new()
+    } else {
+        format!("{} ", columns.join(" "))
+    }
+}
@@ -0,0 +1,172 @@
+//! humanized durations, stopwatches and the elapsed time between [step](crate::step) calls
+use crate::color::{ansi, auto, fore, pad};
+use crate::gradient::heat_color;
+use crate::output::columns;
+use std::cell::Cell;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// `log10` of the nanoseconds colored green by [duration_color] (1µs)
+const FAST: f64 = 3.0;
+/// `log10` of the nanoseconds colored red by [duration_color] (1s)
+const SLOW: f64 = 9.0;
+
+static STEP_ELAPSED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static LAST_STEP: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+/// formats the given duration in its most readable unit (i.e.: `850ns`, `12.3ms`, `1.25s`, `2m03s`)
+pub fn humanize_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    let secs = duration.as_secs();
+    if nanos < 1_000 {
+        format!("{nanos}ns")
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.1}ms", nanos as f64 / 1e6)
+    } else if secs < 60 {
+        format!("{:.2}s", duration.as_secs_f64())
+    } else if secs < 3600 {
+        format!("{}m{:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}h{:02}m{:02}s", secs / 3600, secs % 3600 / 60, secs % 60)
+    }
+}
+
+/// ANSI-256 color of the given duration by order of magnitude: green
+/// (1µs or less) => yellow => red (1s or more)
+pub fn duration_color(duration: Duration) -> u8 {
+    let magnitude = (duration.as_nanos().max(1) as f64).log10();
+    heat_color(magnitude, FAST, SLOW)
+}
+
+/// [humanize_duration] colored by [duration_color]
+pub fn format_duration(duration: Duration) -> String {
+    fore(humanize_duration(duration), duration_color(duration).into())
+}
+
+/// makes [step](crate::step) show the time elapsed since the previous
+/// [step](crate::step) on the same thread
+pub fn set_step_elapsed(enabled: bool) {
+    STEP_ELAPSED.store(enabled, Ordering::Relaxed);
+}
+
+/// whether [step](crate::step) shows the time elapsed since the previous [step](crate::step)
+pub fn step_elapsed() -> bool {
+    STEP_ELAPSED.load(Ordering::Relaxed)
+}
+
+/// marks a [step](crate::step) on the current thread and returns the time
+/// elapsed since the previous one, if any
+pub fn since_last_step() -> Option<Duration> {
+    let now = Instant::now();
+    LAST_STEP
+        .with(|last| last.replace(Some(now)))
+        .map(|last| now.duration_since(last))
+}
+
+/// formats the location line of [step](crate::step), followed by the
+/// output of [since_last_step] when [step_elapsed] is enabled
+pub fn format_step_header<T: Display>(header: T, fg: usize, bg: usize) -> String {
+    match since_last_step().filter(|_| step_elapsed()) {
+        Some(elapsed) => {
+            let text = format!(" +{} ", humanize_duration(elapsed));
+            let width = columns().saturating_sub(text.chars().count());
+            format!(
+                "{}{}",
+                ansi(pad(header, width), fg, bg),
+                ansi(text, duration_color(elapsed).into(), bg)
+            )
+        }
+        None => ansi(pad(header, columns()), fg, bg),
+    }
+}
+
+/// measures named laps
+///
+/// ```
+/// use debug_et_diagnostics::Stopwatch;
+///
+/// let mut stopwatch = Stopwatch::new("parse");
+/// stopwatch.lap("tokenize");
+/// stopwatch.lap("build tree");
+/// eprintln!("{stopwatch}");
+/// ```
+#[derive(Debug, Clone)]
+pub struct Stopwatch {
+    name: String,
+    start: Instant,
+    last: Instant,
+    laps: Vec<(String, Duration)>,
+}
+
+impl Stopwatch {
+    /// starts a new stopwatch
+    pub fn new<T: Display>(name: T) -> Stopwatch {
+        let start = Instant::now();
+        Stopwatch {
+            name: name.to_string(),
+            start,
+            last: start,
+            laps: Vec::new(),
+        }
+    }
+
+    /// name of the stopwatch
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// records a lap with the time elapsed since the previous lap (or the start) and returns it
+    pub fn lap<T: Display>(&mut self, name: T) -> Duration {
+        let now = Instant::now();
+        let duration = now.duration_since(self.last);
+        self.last = now;
+        self.laps.push((name.to_string(), duration));
+        duration
+    }
+
+    /// laps recorded so far
+    pub fn laps(&self) -> &[(String, Duration)] {
+        &self.laps
+    }
+
+    /// time elapsed since the stopwatch started
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// the name and total elapsed time followed by each lap, indented
+    pub fn report(&self) -> String {
+        let width = self
+            .laps
+            .iter()
+            .map(|(name, _)| name.chars().count())
+            .max()
+            .unwrap_or(0);
+        let mut lines = vec![format!(
+            "{} {}",
+            auto(&self.name),
+            format_duration(self.elapsed())
+        )];
+        lines.extend(self.laps.iter().map(|(name, duration)| {
+            format!(
+                "    {}{} {}",
+                auto(name),
+                " ".repeat(width - name.chars().count()),
+                format_duration(*duration)
+            )
+        }));
+        lines.join("\n")
+    }
+}
+
+impl Display for Stopwatch {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.report())
+    }
+}
@@ -0,0 +1,36 @@
+//! serialization of the tests which change process-wide settings (i.e.:
+//! [set_theme](debug_et_diagnostics::set_theme)) within a test binary
+#![allow(dead_code)]
+use std::sync::{Mutex, MutexGuard};
+
+static SETTINGS: Mutex<()> = Mutex::new(());
+
+/// held by tests which depend on process-wide settings changed by other
+/// tests of the same binary
+pub fn lock() -> MutexGuard<'static, ()> {
+    SETTINGS.lock().unwrap_or_else(|error| error.into_inner())
+}
+
+/// holds [lock] and restores the settings read via `current` on drop,
+/// even if the test panics
+pub struct Settings<T, F: FnOnce(T)> {
+    previous: Option<(T, F)>,
+    _lock: MutexGuard<'static, ()>,
+}
+
+/// acquires [lock], then reads the current settings to be restored via `restore`
+pub fn settings<T, F: FnOnce(T)>(current: impl FnOnce() -> T, restore: F) -> Settings<T, F> {
+    let lock = lock();
+    Settings {
+        previous: Some((current(), restore)),
+        _lock: lock,
+    }
+}
+
+impl<T, F: FnOnce(T)> Drop for Settings<T, F> {
+    fn drop(&mut self) {
+        if let Some((previous, restore)) = self.previous.take() {
+            restore(previous);
+        }
+    }
+}
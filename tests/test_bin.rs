@@ -0,0 +1,132 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+fn run(args: &[&str]) -> (bool, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_debug-et-diagnostics"))
+        .args(args)
+        .output()
+        .expect("failed to run debug-et-diagnostics");
+    (
+        output.status.success(),
+        String::from_utf8_lossy(&output.stdout).to_string(),
+    )
+}
+
+fn pipe(args: &[&str], stdin: &[u8]) -> (bool, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_debug-et-diagnostics"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("failed to run debug-et-diagnostics");
+    child.stdin.take().unwrap().write_all(stdin).unwrap();
+    let output = child.wait_with_output().unwrap();
+    (
+        output.status.success(),
+        String::from_utf8_lossy(&output.stdout).to_string(),
+    )
+}
+
+#[test]
+fn test_palette() {
+    let (success, stdout) = run(&["palette"]);
+    assert!(success);
+    assert_eq!(stdout.lines().count(), 14 + 1 + 256);
+    assert!(stdout.contains("#d70000"));
+    assert!(stdout.contains("invert_ansi"));
+}
+
+#[test]
+fn test_palette_index() {
+    let (success, stdout) = run(&["palette", "196"]);
+    assert!(success);
+    assert_eq!(stdout.lines().count(), 1);
+    assert!(stdout.contains("#ff0000"));
+    assert!(stdout.contains("red"));
+}
+
+#[test]
+fn test_palette_text() {
+    let (success, stdout) = run(&["palette", "identifier"]);
+    assert!(success);
+    assert!(stdout.contains("from_display"));
+    assert!(stdout.contains("from_debug"));
+    assert!(stdout.contains("rgb_from_display"));
+}
+
+#[test]
+fn test_palette_numeric_text() {
+    let (success, stdout) = run(&["palette", "--text", "42"]);
+    assert!(success);
+    assert!(stdout.contains("from_display"));
+    assert!(!stdout.contains("invert_ansi"));
+}
+
+#[test]
+fn test_palette_index_out_of_range() {
+    let (success, stdout) = run(&["palette", "300"]);
+    assert!(!success);
+    assert!(stdout.is_empty());
+}
+
+#[test]
+fn test_unknown_command() {
+    let (success, _) = run(&["unknown"]);
+    assert!(!success);
+}
+
+#[test]
+fn test_strip() {
+    let (success, stdout) = pipe(&["strip"], b"\x1b[1;38;5;220mhello\x1b[0m world\n");
+    assert!(success);
+    assert_eq!(stdout, "hello world\n");
+}
+
+#[test]
+fn test_strip_invalid_utf8() {
+    let (success, stdout) = pipe(&["strip"], b"ok\n\xff\xfebad\nafter\n");
+    assert!(success);
+    assert_eq!(stdout, "ok\n\u{fffd}\u{fffd}bad\nafter\n");
+}
+
+#[test]
+fn test_colorize_invalid_utf8() {
+    let (success, stdout) = pipe(&["colorize"], b"ok\n\xff\xfebad\nafter\n");
+    assert!(success);
+    assert_eq!(stdout.lines().count(), 3);
+}
+
+#[test]
+fn test_colorize() {
+    let (success, stdout) = pipe(&["colorize"], b"id=42\nid=42\n");
+    assert!(success);
+    let lines = stdout.lines().collect::<Vec<&str>>();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0], lines[1]);
+    assert!(lines[0].contains("\x1b["));
+}
+
+#[test]
+fn test_colorize_tokens() {
+    let (success, stdout) = pipe(&["colorize", "--tokens"], b"alpha  beta alpha\n");
+    assert!(success);
+    let colored = stdout.split("  ").collect::<Vec<&str>>();
+    assert_eq!(colored.len(), 2);
+    assert!(colored[1].ends_with(&format!("{}\n", colored[0])));
+}
+
+#[test]
+fn test_hexdump() {
+    let (success, stdout) = pipe(&["hexdump"], &[0u8; 20]);
+    assert!(success);
+    assert_eq!(stdout.lines().count(), 2);
+}
+
+#[test]
+fn test_byte() {
+    let (success, stdout) = run(&["byte", "0x41", "0b1", "A", "10"]);
+    assert!(success);
+    assert_eq!(stdout.lines().count(), 4);
+    let (success, _) = run(&["byte", "300"]);
+    assert!(!success);
+}
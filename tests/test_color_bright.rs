@@ -2,16 +2,16 @@ use debug_et_diagnostics::{bright};
 
 #[test]
 fn test_bright() {
-    assert_eq!(bright(0), 102);
+    assert_eq!(bright(0), 241);
     assert_eq!(bright(1), 210);
-    assert_eq!(bright(2), 120);
-    assert_eq!(bright(3), 228);
-    assert_eq!(bright(4), 105);
+    assert_eq!(bright(2), 83);
+    assert_eq!(bright(3), 191);
+    assert_eq!(bright(4), 111);
     assert_eq!(bright(5), 213);
-    assert_eq!(bright(6), 123);
-    assert_eq!(bright(7), 231);
-    assert_eq!(bright(8), 231);
-    assert_eq!(bright(9), 231);
+    assert_eq!(bright(6), 87);
+    assert_eq!(bright(7), 255);
+    assert_eq!(bright(8), 250);
+    assert_eq!(bright(9), 217);
     // assert_eq!(bright(10), 231);
     // assert_eq!(bright(11), 255);
     // assert_eq!(bright(12), 147);
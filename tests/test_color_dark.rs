@@ -3,21 +3,21 @@ use debug_et_diagnostics::{dark};
 #[test]
 fn test_dark() {
     assert_eq!(dark(0), 16);
-    assert_eq!(dark(1), 16);
-    assert_eq!(dark(2), 16);
-    assert_eq!(dark(3), 16);
-    assert_eq!(dark(4), 16);
-    assert_eq!(dark(5), 16);
-    assert_eq!(dark(6), 16);
-    assert_eq!(dark(7), 16);
-    assert_eq!(dark(8), 16);
-    assert_eq!(dark(9), 16);
-    assert_eq!(dark(10), 16);
-    assert_eq!(dark(11), 16);
-    assert_eq!(dark(12), 16);
-    assert_eq!(dark(13), 16);
-    assert_eq!(dark(14), 16);
-    assert_eq!(dark(15), 102);
+    assert_eq!(dark(1), 52);
+    assert_eq!(dark(2), 22);
+    assert_eq!(dark(3), 58);
+    assert_eq!(dark(4), 17);
+    assert_eq!(dark(5), 53);
+    assert_eq!(dark(6), 23);
+    assert_eq!(dark(7), 240);
+    assert_eq!(dark(8), 236);
+    assert_eq!(dark(9), 52);
+    assert_eq!(dark(10), 22);
+    assert_eq!(dark(11), 58);
+    assert_eq!(dark(12), 18);
+    assert_eq!(dark(13), 53);
+    assert_eq!(dark(14), 23);
+    assert_eq!(dark(15), 241);
     assert_eq!(dark(16), 16);
     // assert_eq!(dark(17), 23);
     // assert_eq!(dark(18), 63);
@@ -0,0 +1,135 @@
+#![allow(dead_code)]
+mod common;
+
+use debug_et_diagnostics::{ColorDebug, Theme, fore, set_theme, strip_ansi, theme};
+
+#[derive(Debug, ColorDebug)]
+struct Credentials {
+    user: String,
+    #[color_debug(redact)]
+    password: String,
+    #[color_debug(skip)]
+    attempts: u32,
+}
+
+#[derive(Debug, ColorDebug)]
+struct Packet<T> {
+    id: T,
+    #[color_debug(hex)]
+    flags: u8,
+    #[color_debug(bytes)]
+    payload: Vec<u8>,
+}
+
+#[derive(Debug, ColorDebug)]
+struct Point(i32, i32);
+
+#[derive(Debug, ColorDebug)]
+struct Unit;
+
+#[derive(Debug, ColorDebug)]
+struct Account {
+    credentials: Credentials,
+    origin: Point,
+}
+
+#[derive(Debug, ColorDebug)]
+enum Token {
+    Word(String),
+    Number { value: i64, r#type: &'static str },
+    End,
+}
+
+#[test]
+fn test_color_debug_struct() {
+    let credentials = Credentials {
+        user: "gabriel".to_string(),
+        password: "hunter2".to_string(),
+        attempts: 3,
+    };
+    assert_eq!(
+        strip_ansi(credentials.color_debug()),
+        "Credentials {\n    user: \"gabriel\",\n    password: <redacted>,\n}"
+    );
+    assert_eq!(
+        strip_ansi(Point(1, -2).color_debug()),
+        "Point(\n    1,\n    -2,\n)"
+    );
+    assert_eq!(strip_ansi(Unit.color_debug()), "Unit");
+}
+
+#[test]
+fn test_color_debug_nested() {
+    let _lock = common::lock();
+    let account = Account {
+        credentials: Credentials {
+            user: "gabriel".to_string(),
+            password: "hunter2".to_string(),
+            attempts: 3,
+        },
+        origin: Point(0, 0),
+    };
+    let rendered = account.color_debug();
+    assert_eq!(
+        strip_ansi(&rendered),
+        "Account {\n    credentials: Credentials {\n        user: \"gabriel\",\n        password: <redacted>,\n    },\n    origin: Point(\n        0,\n        0,\n    ),\n}"
+    );
+    assert!(rendered.contains(&fore("user", Theme::DEFAULT.field.into())));
+}
+
+#[test]
+fn test_color_debug_attributes() {
+    let packet = Packet {
+        id: 7u16,
+        flags: 255,
+        payload: b"hi".to_vec(),
+    };
+    let rendered = strip_ansi(packet.color_debug());
+    assert!(rendered.contains("    id: 7,\n"));
+    assert!(rendered.contains("    flags: 0xff,\n"));
+    assert!(rendered.contains("    payload: [0x68, 0x69] => \"hi\",\n"));
+}
+
+#[test]
+fn test_color_debug_enum() {
+    assert_eq!(
+        strip_ansi(Token::Word("hello".to_string()).color_debug()),
+        "Word(\n    \"hello\",\n)"
+    );
+    assert_eq!(
+        strip_ansi(
+            Token::Number {
+                value: 42,
+                r#type: "i64"
+            }
+            .color_debug()
+        ),
+        "Number {\n    value: 42,\n    type: \"i64\",\n}"
+    );
+    assert_eq!(strip_ansi(Token::End.color_debug()), "End");
+}
+
+#[test]
+fn test_color_debug_theme() {
+    let _settings = common::settings(theme, set_theme);
+    assert_eq!(theme(), Theme::DEFAULT);
+    let rendered = Point(1, 2).color_debug();
+    assert!(rendered.starts_with(&fore("Point", Theme::DEFAULT.type_name.into())));
+
+    let custom = Theme {
+        type_name: 33,
+        field: 208,
+        ..Theme::DEFAULT
+    };
+    set_theme(custom);
+    assert_eq!(theme(), custom);
+    assert!(
+        Point(1, 2)
+            .color_debug()
+            .starts_with(&fore("Point", custom.type_name.into()))
+    );
+    let rendered = Unit.color_debug();
+    set_theme(Theme::DEFAULT);
+    assert_eq!(rendered, fore("Unit", custom.type_name.into()));
+    assert_eq!(theme(), Theme::DEFAULT);
+}
@@ -1,4 +1,4 @@
-use debug_et_diagnostics::{from_byte, from_bytes};
+use debug_et_diagnostics::from_byte;
 
 #[test]
 fn test_from_byte() {
@@ -1,3 +1,4 @@
+#![allow(non_snake_case)]
 use debug_et_diagnostics::{from_debug};
 
 #[test]
@@ -0,0 +1,90 @@
+use debug_et_diagnostics::{
+    bright, dark, delta_e, get_ansi_rgb, hsl_to_rgb, hsv_to_rgb, invert_ansi, invert_bw,
+    lab_to_rgb, lighten, oklab_to_oklch, oklab_to_rgb, oklch_to_oklab, oklch_to_rgb, rgb_to_byte,
+    rgb_to_hsl, rgb_to_hsv, rgb_to_lab, rgb_to_oklab,
+};
+
+fn assert_close(actual: [f64; 3], expected: [f64; 3], tolerance: f64) {
+    for (a, e) in actual.iter().zip(expected.iter()) {
+        assert!((a - e).abs() <= tolerance, "{actual:?} != {expected:?}");
+    }
+}
+
+#[test]
+fn test_hsl() {
+    assert_close(rgb_to_hsl([255, 0, 0]), [0.0, 100.0, 50.0], 0.01);
+    assert_close(rgb_to_hsl([0, 102, 153]), [200.0, 100.0, 30.0], 0.01);
+    assert_close(rgb_to_hsl([128, 128, 128]), [0.0, 0.0, 50.2], 0.01);
+    assert_eq!(hsl_to_rgb([48.0, 70.0, 50.0]), [217, 181, 38]);
+    assert_eq!(hsl_to_rgb([359.0, 33.0, 77.0]), [216, 177, 178]);
+}
+
+#[test]
+fn test_hsv() {
+    assert_close(rgb_to_hsv([255, 0, 0]), [0.0, 100.0, 100.0], 0.01);
+    assert_close(rgb_to_hsv([0, 128, 0]), [120.0, 100.0, 50.2], 0.01);
+    assert_eq!(hsv_to_rgb([240.0, 100.0, 100.0]), [0, 0, 255]);
+    assert_eq!(hsv_to_rgb([0.0, 0.0, 50.0]), [128, 128, 128]);
+}
+
+#[test]
+fn test_oklab() {
+    assert_close(rgb_to_oklab([255, 255, 255]), [1.0, 0.0, 0.0], 0.001);
+    assert_close(rgb_to_oklab([255, 0, 0]), [0.628, 0.2249, 0.1258], 0.001);
+    assert_close(oklab_to_oklch([0.5, 0.0, 0.1]), [0.5, 0.1, 90.0], 0.0001);
+    assert_close(oklch_to_oklab([0.5, 0.1, 90.0]), [0.5, 0.0, 0.1], 0.0001);
+}
+
+#[test]
+fn test_lab() {
+    assert_close(rgb_to_lab([255, 255, 255]), [100.0, 0.0, 0.0], 0.01);
+    assert_close(rgb_to_lab([255, 0, 0]), [53.24, 80.09, 67.20], 0.05);
+    assert_eq!(delta_e([10, 20, 30], [10, 20, 30]), 0.0);
+    assert!(delta_e([255, 0, 0], [0, 255, 0]) > 100.0);
+}
+
+#[test]
+fn test_round_trips() {
+    for rgb in [
+        [0, 0, 0],
+        [255, 255, 255],
+        [255, 136, 0],
+        [18, 52, 86],
+        [175, 95, 215],
+    ] {
+        assert_eq!(hsl_to_rgb(rgb_to_hsl(rgb)), rgb);
+        assert_eq!(hsv_to_rgb(rgb_to_hsv(rgb)), rgb);
+        assert_eq!(oklab_to_rgb(rgb_to_oklab(rgb)), rgb);
+        assert_eq!(lab_to_rgb(rgb_to_lab(rgb)), rgb);
+    }
+}
+
+#[test]
+fn test_oklch_to_rgb_gamut() {
+    assert_eq!(
+        oklch_to_rgb(oklab_to_oklch(rgb_to_oklab([255, 136, 0]))),
+        [255, 136, 0]
+    );
+    let [lightness, _, hue] = oklab_to_oklch(rgb_to_oklab(oklch_to_rgb([0.9, 0.4, 30.0])));
+    assert!((lightness - 0.9).abs() < 0.01);
+    assert!((hue - 30.0).abs() < 2.0);
+}
+
+#[test]
+fn test_perceptual_color_helpers() {
+    let lightness = |color: u8| rgb_to_oklab(get_ansi_rgb(color as usize))[0];
+    for color in 16..=255u8 {
+        assert_eq!(
+            lighten(color as usize, 0.0),
+            rgb_to_byte(get_ansi_rgb(color as usize))
+        );
+        assert!(lightness(bright(color as usize)) >= lightness(color) - 0.02);
+        assert!(lightness(dark(color as usize)) <= lightness(color) + 0.02);
+    }
+    for color in [160usize, 34, 21, 220] {
+        let inverted = lightness(invert_ansi(color));
+        assert!((inverted - (1.0 - lightness(color as u8))).abs() < 0.15);
+    }
+    assert_eq!(invert_bw(16), 231);
+    assert_eq!(invert_bw(231), 16);
+}
@@ -0,0 +1,11 @@
+use debug_et_diagnostics::{fore, strip_ansi};
+
+#[test]
+fn test_strip_ansi() {
+    assert_eq!(strip_ansi(fore("text", 220)), "text");
+    assert_eq!(
+        strip_ansi("\x1b]8;;file:///tmp\x1b\\link\x1b]8;;\x1b\\"),
+        "link"
+    );
+    assert_eq!(strip_ansi("plain"), "plain");
+}
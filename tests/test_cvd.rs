@@ -0,0 +1,107 @@
+mod common;
+
+use debug_et_diagnostics::{
+    ColorAllocator, Deficiency, cvd_safe, cvd_safe_color, cvd_safe_palette, deuteranopia,
+    from_display, get_ansi_rgb, nearest_cvd_safe, oklab_distance, protanopia, rgb_distance,
+    set_cvd_safe, simulate_rgb, tritanopia,
+};
+
+#[test]
+fn test_simulate_preserves_grayscale() {
+    for gray in [[0, 0, 0], [127, 127, 127], [255, 255, 255]] {
+        for deficiency in Deficiency::ALL {
+            let simulated = simulate_rgb(gray, deficiency);
+            assert!(
+                rgb_distance(gray, simulated) < 4.0,
+                "{deficiency:?} {gray:?} => {simulated:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_red_green_confusion() {
+    let red = get_ansi_rgb(160);
+    let green = get_ansi_rgb(34);
+    assert!(rgb_distance(red, green) > 150.0);
+    assert!(rgb_distance(protanopia(red), protanopia(green)) < rgb_distance(red, green) / 2.0);
+    assert!(rgb_distance(deuteranopia(red), deuteranopia(green)) < rgb_distance(red, green) / 2.0);
+    assert!(rgb_distance(tritanopia(red), tritanopia(green)) > rgb_distance(red, green) / 2.0);
+}
+
+#[test]
+fn test_cvd_safe_palette() {
+    for deficiency in Deficiency::ALL {
+        let palette = cvd_safe_palette(deficiency);
+        assert!(palette.len() > 16, "{deficiency:?} {palette:?}");
+        for a in palette {
+            for b in palette.iter().filter(|b| *b != a) {
+                let a = simulate_rgb(get_ansi_rgb(*a as usize), deficiency);
+                let b = simulate_rgb(get_ansi_rgb(*b as usize), deficiency);
+                assert!(oklab_distance(a, b) >= 0.1);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_cvd_safe_mode() {
+    let _settings = common::settings(cvd_safe, set_cvd_safe);
+    assert_eq!(cvd_safe(), None);
+    assert_eq!(cvd_safe_color(160), 160);
+    assert_eq!(from_display("R"), 160);
+
+    set_cvd_safe(Some(Deficiency::Deuteranopia));
+    assert_eq!(cvd_safe(), Some(Deficiency::Deuteranopia));
+    let palette = cvd_safe_palette(Deficiency::Deuteranopia);
+    assert!(palette.contains(&cvd_safe_color(160)));
+    assert!(palette.contains(&from_display("R")));
+    assert!(palette.contains(&from_display("identifier")));
+
+    let mut allocator = ColorAllocator::new();
+    let colors = ["main", "worker-1", "worker-2", "worker-3"].map(|key| allocator.color(key));
+    for a in colors {
+        assert!(palette.contains(&a));
+        for b in colors.iter().filter(|b| **b != a) {
+            let a = simulate_rgb(get_ansi_rgb(a as usize), Deficiency::Deuteranopia);
+            let b = simulate_rgb(get_ansi_rgb(*b as usize), Deficiency::Deuteranopia);
+            assert!(oklab_distance(a, b) >= 0.1);
+        }
+    }
+
+    set_cvd_safe(None);
+    assert_eq!(from_display("R"), 160);
+}
+
+#[test]
+fn test_nearest_cvd_safe() {
+    for deficiency in Deficiency::ALL {
+        let palette = cvd_safe_palette(deficiency);
+        for color in palette {
+            assert_eq!(nearest_cvd_safe(*color as usize, deficiency), *color);
+        }
+        for color in 0..=255usize {
+            let nearest = nearest_cvd_safe(color, deficiency);
+            let rgb = get_ansi_rgb(color);
+            let distance = oklab_distance(rgb, get_ansi_rgb(nearest as usize));
+            assert!(
+                palette
+                    .iter()
+                    .all(|other| oklab_distance(rgb, get_ansi_rgb(*other as usize)) >= distance)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_color_allocator() {
+    let _lock = common::lock();
+    let mut allocator = ColorAllocator::new();
+    assert!(allocator.is_empty());
+    let first = allocator.color("main");
+    let second = allocator.color("worker");
+    assert_ne!(first, second);
+    assert_eq!(allocator.color("main"), first);
+    assert_eq!(allocator.color("worker"), second);
+    assert_eq!(allocator.len(), 2);
+}
@@ -0,0 +1,57 @@
+use debug_et_diagnostics::{dbg, format_dbg, format_dbg_location, strip_ansi};
+use std::cell::Cell;
+
+#[test]
+fn test_dbg_returns_value() {
+    assert_eq!(dbg!(1 + 1), 2);
+    let text = String::from("moved");
+    let text = dbg!(text);
+    assert_eq!(text, "moved");
+}
+
+#[test]
+fn test_dbg_returns_tuple() {
+    let (a, b, c) = dbg!(1, "two", vec![3]);
+    assert_eq!((a, b, c), (1, "two", vec![3]));
+    let (depth, items) = dbg!(depth = 1, 4u8, [5u8; 2]);
+    assert_eq!((depth, items), (4, [5, 5]));
+}
+
+#[test]
+fn test_dbg_evaluates_once() {
+    let calls = Cell::new(0);
+    let next = || {
+        calls.set(calls.get() + 1);
+        calls.get()
+    };
+    assert_eq!(dbg!(next()), 1);
+    assert_eq!(dbg!(next(), next()), (2, 3));
+    assert_eq!(dbg!("labeled" => next()), 4);
+    assert_eq!(dbg!(fmt = "{:x}", next()), 5);
+    assert_eq!(calls.get(), 5);
+}
+
+#[test]
+fn test_dbg_labels() {
+    let header = [0xcau8, 0xfe];
+    assert_eq!(dbg!("parsed header" => header), header);
+    assert_eq!(dbg!("a" => 1, "b" => 2), (1, 2));
+    assert_eq!(
+        strip_ansi(format_dbg!("parsed header" => header)),
+        "    parsed header = [\n        202,\n        254,\n    ]"
+    );
+}
+
+#[test]
+fn test_dbg_fmt() {
+    assert_eq!(dbg!(fmt = "{:x}", 255), 255);
+    assert_eq!(dbg!(fmt = "{:08b}", 1u8, 2u8), (1, 2));
+    assert_eq!(
+        strip_ansi(format_dbg!(fmt = "{:#x}", 255)),
+        "    &255 = 0xff"
+    );
+    let formatted = strip_ansi(format_dbg_location!(fmt = "{:x}", 255));
+    assert!(formatted.starts_with("<in function test_dbg_fmt"));
+    assert!(formatted.contains("&255 = ff"));
+    assert!(formatted.ends_with(">"));
+}
@@ -0,0 +1,103 @@
+mod common;
+
+use debug_et_diagnostics::{
+    SourceLocation, diag, format_filename, format_location, location, set_source_root,
+    set_source_snippets, source_root, source_snippets, strip_ansi,
+};
+
+#[track_caller]
+fn checkpoint(name: &str) -> [String; 2] {
+    diag::step(name);
+    diag::info(format!("reached {name}"));
+    [
+        diag::format_caller_step(name),
+        diag::format_caller_info(format!("reached {name}")),
+    ]
+}
+
+#[track_caller]
+fn inspect<T: std::fmt::Debug>(value: &T) -> String {
+    diag::dbg(value);
+    diag::format_caller_dbg(value)
+}
+
+#[test]
+fn test_diag() {
+    let ([step, info], line) = (checkpoint("first"), line!());
+    assert!(strip_ansi(step).contains(&format!("test_diag.rs:{line}")));
+    assert_eq!(
+        strip_ansi(info),
+        format!("\ntests/test_diag.rs:{line} INFO reached first")
+    );
+    let (warning, line) = (diag::format_caller_warn("careful"), line!());
+    assert_eq!(
+        strip_ansi(warning),
+        format!("\ntests/test_diag.rs:{line} WARNING careful")
+    );
+    diag::warn("careful");
+
+    let (dbg, line) = (inspect(&vec![1, 2, 3]), line!());
+    let dbg = strip_ansi(dbg);
+    assert!(dbg.starts_with(&format!("<dbg at test_diag.rs:{line}>\n")));
+    assert!(!dbg.contains("function"));
+    assert!(dbg.contains("Vec<i32> = [\n"));
+    diag::dbg("str");
+
+    let _settings = common::settings(
+        || (source_snippets(), source_root()),
+        |(snippets, root)| {
+            set_source_snippets(snippets);
+            set_source_root(root);
+        },
+    );
+    set_source_snippets(true);
+    checkpoint("without source root");
+    set_source_root(Some(env!("CARGO_MANIFEST_DIR").into()));
+    checkpoint("with snippet");
+}
+
+#[test]
+fn test_format_location() {
+    let here = |file| SourceLocation {
+        crate_name: None,
+        manifest_dir: None,
+        file,
+        line: 3,
+        column: 1,
+    };
+    assert_eq!(strip_ansi(format_filename(&here("src/lib.rs"))), "lib.rs");
+    assert_eq!(
+        strip_ansi(format_filename(&here("crate/src/lib.rs"))),
+        "lib.rs in crate/src"
+    );
+    assert_eq!(
+        strip_ansi(format_location(None, &here("src/lib.rs"))),
+        "lib.rs:3"
+    );
+    let (location, line) = (location!(), line!());
+    assert_eq!(
+        strip_ansi(location),
+        format!("test_format_location @ test_diag.rs:{line}")
+    );
+}
+
+#[test]
+fn test_format_step() {
+    let banner = strip_ansi(diag::format_step(
+        "main src/main.rs:1",
+        "parse",
+        16,
+        231,
+        10,
+        false,
+    ));
+    let lines = banner.lines().collect::<Vec<&str>>();
+    assert_eq!(lines[0], "");
+    assert_eq!(lines[1], " ".repeat(10));
+    assert!(lines[2].starts_with("main src/main.rs:1"));
+    assert!(lines[3].starts_with("parse"));
+    assert_eq!(
+        strip_ansi(diag::format_admonition("main:1 INFO", "message", 74)),
+        "\nmain:1 INFO message"
+    );
+}
@@ -0,0 +1,118 @@
+#![cfg_attr(feature = "backtrace", feature(error_generic_member_access))]
+#[cfg(feature = "backtrace")]
+use debug_et_diagnostics::error_backtrace;
+use debug_et_diagnostics::{ResultExt, dbg_err, error_chain, format_error, strip_ansi};
+#[cfg(feature = "backtrace")]
+use std::backtrace::Backtrace;
+use std::error::Error;
+#[cfg(feature = "backtrace")]
+use std::error::Request;
+use std::fmt;
+
+#[derive(Debug)]
+struct Io;
+
+impl fmt::Display for Io {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "connection reset")
+    }
+}
+
+impl Error for Io {}
+
+#[derive(Debug)]
+struct Fetch(Io);
+
+impl fmt::Display for Fetch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to fetch index")
+    }
+}
+
+impl Error for Fetch {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+#[test]
+fn test_error_chain() {
+    let error = Fetch(Io);
+    let chain = error_chain(&error)
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>();
+    assert_eq!(chain, vec!["failed to fetch index", "connection reset"]);
+    #[cfg(feature = "backtrace")]
+    assert!(error_backtrace(&error).is_none());
+}
+
+#[test]
+fn test_format_error() {
+    assert_eq!(
+        strip_ansi(format_error(&Fetch(Io))),
+        [
+            "<error>",
+            "    failed to fetch index",
+            "    <caused by>",
+            "        connection reset",
+            "    </caused by>",
+            "</error>",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn test_dbg_err() {
+    let error = dbg_err!(Fetch(Io));
+    assert_eq!(error.to_string(), "failed to fetch index");
+    let boxed: Box<dyn Error> = Box::new(Io);
+    let boxed = dbg_err!(boxed);
+    assert_eq!(boxed.to_string(), "connection reset");
+}
+
+#[test]
+fn test_result_dbg_err() {
+    let result: Result<u8, Fetch> = Err(Fetch(Io));
+    assert!(result.dbg_err().is_err());
+    let result: Result<u8, Fetch> = Ok(3);
+    assert_eq!(result.dbg_err().unwrap(), 3);
+    let result: Result<u8, Box<dyn Error>> = Err(Box::new(Fetch(Io)));
+    assert_eq!(
+        result.dbg_err().unwrap_err().to_string(),
+        "failed to fetch index"
+    );
+    let result: Result<u8, Box<dyn Error + Send + Sync>> = Err("invalid digit".into());
+    assert_eq!(result.dbg_err().unwrap_err().to_string(), "invalid digit");
+    let result: Result<u8, Box<Io>> = Err(Box::new(Io));
+    assert!(result.dbg_err().is_err());
+}
+
+#[cfg(feature = "backtrace")]
+#[derive(Debug)]
+struct Traced(Backtrace);
+
+#[cfg(feature = "backtrace")]
+impl fmt::Display for Traced {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "traced")
+    }
+}
+
+#[cfg(feature = "backtrace")]
+impl Error for Traced {
+    fn provide<'a>(&'a self, request: &mut Request<'a>) {
+        request.provide_ref::<Backtrace>(&self.0);
+    }
+}
+
+#[test]
+#[cfg(feature = "backtrace")]
+fn test_format_error_backtrace() {
+    let error = Traced(Backtrace::force_capture());
+    assert!(error_backtrace(&error).is_some());
+    let formatted = strip_ansi(format_error(&error));
+    assert!(formatted.starts_with("<error>\n    traced\n    <backtrace>\n"));
+    assert!(formatted.ends_with("    </backtrace>\n</error>"));
+}
@@ -0,0 +1,85 @@
+#![allow(clippy::redundant_closure_call)]
+use debug_et_diagnostics::{FunctionName, Marker, function, function_name, strip_ansi};
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+trait Greet {
+    fn greet(&self) -> Vec<FunctionName>;
+}
+
+struct Person<T>(T);
+
+impl<T> Greet for Person<T> {
+    fn greet(&self) -> Vec<FunctionName> {
+        vec![function!(), (|| function!())()]
+    }
+}
+
+impl<T> Person<T> {
+    fn name(&self) -> String {
+        function_name!()
+    }
+}
+
+#[test]
+fn test_function_name_parse() {
+    let name = FunctionName::parse("<app::m::Bar<_> as app::m::Foo>::go::f", "app::m");
+    assert_eq!(name.impl_type.as_deref(), Some("Bar"));
+    assert_eq!(name.trait_name.as_deref(), Some("Foo"));
+    assert_eq!(name.function, "go");
+    assert_eq!(name.to_string(), "<Bar as Foo>::go");
+
+    let name = FunctionName::parse("<impl app::Foo for app::Bar>::go::{{closure}}::f", "app");
+    assert_eq!(name.to_string(), "<Bar as Foo>::go λ");
+
+    let name = FunctionName::parse("app::m::Bar<_>::inherent::f", "app::m");
+    assert_eq!(name.impl_type.as_deref(), Some("Bar"));
+    assert_eq!(name.trait_name, None);
+    assert_eq!(name.to_string(), "Bar::inherent");
+
+    let name = FunctionName::parse("app::m::outer::inner::f", "app::m");
+    assert_eq!(name.impl_type, None);
+    assert_eq!(name.to_string(), "outer::inner");
+
+    let name = FunctionName::parse("app::m::asy::{{closure}}::{{closure}}::f", "app::m");
+    assert_eq!(name.markers, vec![Marker::Closure, Marker::Closure]);
+    assert_eq!(name.closure_depth(), 2);
+    assert_eq!(name.module, "app::m");
+    assert_eq!(name.to_string(), "asy λλ");
+
+    let name = FunctionName::parse("app::m::run::{{constant}}::f", "app::m");
+    assert_eq!(name.markers, vec![]);
+    assert_eq!(name.to_string(), "run");
+    assert_eq!(Marker::parse("{{constructor}}"), None);
+}
+
+async fn fetch() -> FunctionName {
+    function!()
+}
+
+#[test]
+fn test_function_name_async() {
+    let block = async { (function!(), fetch().await) };
+    let mut context = Context::from_waker(Waker::noop());
+    let Poll::Ready((block, fetch)) = pin!(block).poll(&mut context) else {
+        unreachable!("nothing to await");
+    };
+    // rustc names async blocks and the bodies of async fns as closures
+    assert_eq!(block.markers, vec![Marker::Closure]);
+    assert_eq!(block.to_string(), "test_function_name_async λ");
+    assert_eq!(fetch.to_string(), "fetch λ");
+}
+
+#[test]
+fn test_function_macro() {
+    let name = function!();
+    assert_eq!(name.function, "test_function_macro");
+    assert_eq!(name.module, "test_function");
+    assert_eq!(strip_ansi(name.format()), "test_function_macro");
+    assert_eq!((|| function_name!())(), "test_function_macro λ");
+
+    let names = Person(1).greet();
+    assert_eq!(names[0].to_string(), "<Person as Greet>::greet");
+    assert_eq!(names[1].to_string(), "<Person as Greet>::greet λ");
+    assert_eq!(Person(1).name(), "Person::name");
+}
@@ -0,0 +1,57 @@
+use debug_et_diagnostics::{
+    get_ansi_rgb, gradient, gradient_colors, graphemes, heat_color, interpolate_rgb, rainbow,
+    rainbow_colors, step,
+};
+
+#[test]
+fn test_graphemes() {
+    assert_eq!(graphemes("abc"), vec!["a", "b", "c"]);
+    assert_eq!(graphemes(""), Vec::<&str>::new());
+    assert_eq!(graphemes("e\u{301}x"), vec!["e\u{301}", "x"]);
+    assert_eq!(graphemes("👩\u{200d}💻!"), vec!["👩\u{200d}💻", "!"]);
+    assert_eq!(graphemes("👍🏽"), vec!["👍🏽"]);
+    assert_eq!(graphemes("🇧🇷🇫🇷"), vec!["🇧🇷", "🇫🇷"]);
+}
+
+#[test]
+fn test_interpolate_rgb() {
+    assert_eq!(interpolate_rgb([255, 0, 0], [0, 0, 255], 0.0), [255, 0, 0]);
+    assert_eq!(interpolate_rgb([255, 0, 0], [0, 0, 255], 1.0), [0, 0, 255]);
+    assert_eq!(
+        interpolate_rgb([0, 0, 0], [255, 255, 255], 2.0),
+        [255, 255, 255]
+    );
+}
+
+#[test]
+fn test_gradient_colors() {
+    let colors = gradient_colors(5, 196, 21);
+    assert_eq!(colors.len(), 5);
+    assert_eq!(colors[0], get_ansi_rgb(196));
+    assert_eq!(colors[4], get_ansi_rgb(21));
+    assert_eq!(gradient_colors(1, 196, 21), vec![get_ansi_rgb(196)]);
+    assert_eq!(rainbow_colors(12).len(), 12);
+}
+
+#[test]
+fn test_gradient_text() {
+    let text = gradient("héllo", 196, 21);
+    assert!(text.ends_with("\x1b[0m"));
+    assert_eq!(text.matches("\x1b[1;38;").count(), 5);
+    let text = rainbow("rainbow");
+    assert_eq!(text.matches("\x1b[1;38;").count(), 7);
+}
+
+#[test]
+fn test_heat_color() {
+    assert_eq!(heat_color(0.0, 0.0, 10.0), 77);
+    assert_eq!(heat_color(5.0, 0.0, 10.0), 221);
+    assert_eq!(heat_color(10.0, 0.0, 10.0), 203);
+    assert_eq!(heat_color(100.0, 0.0, 10.0), 203);
+    assert_eq!(heat_color(1.0, 1.0, 1.0), 77);
+}
+
+#[test]
+fn test_step_gradient() {
+    step!(gradient, "text");
+}
@@ -0,0 +1,16 @@
+use debug_et_diagnostics::{format_bytes, strip_ansi};
+
+#[test]
+fn test_hexdump() {
+    assert_eq!(
+        strip_ansi(format_bytes!(dump, b"AB\x00", 16)),
+        "00000010  41 42 00                                          AB."
+    );
+    assert_eq!(format_bytes!(dump, &[0u8; 20]).lines().count(), 2);
+    assert!(
+        strip_ansi(format_bytes!(dump, &[0u8; 20]))
+            .lines()
+            .last()
+            .is_some_and(|line| line.starts_with("00000010  00 00 00 00"))
+    );
+}
@@ -0,0 +1,134 @@
+use debug_et_diagnostics::{
+    Theme, TokenKind, bracket_color, fore, format_dbg, highlight, highlight_debug, indent_objdump,
+    strip_ansi, tokenize,
+};
+
+#[derive(Debug)]
+#[allow(dead_code)]
+struct Person {
+    name: String,
+    initial: char,
+    age: Option<u8>,
+    scores: Vec<f64>,
+    status: Result<bool, ()>,
+}
+
+fn person() -> Person {
+    Person {
+        name: "Gabriel \"Gabe\"".to_string(),
+        initial: '\'',
+        age: Some(37),
+        scores: vec![-1.5, 2e-7],
+        status: Ok(true),
+    }
+}
+
+fn kinds(text: &str) -> Vec<(TokenKind, &str)> {
+    tokenize(text)
+        .into_iter()
+        .filter(|token| token.kind != TokenKind::Whitespace)
+        .map(|token| (token.kind, token.text))
+        .collect()
+}
+
+#[test]
+fn test_tokenize() {
+    assert_eq!(
+        kinds(&format!("{:?}", person())),
+        vec![
+            (TokenKind::TypeName, "Person"),
+            (TokenKind::Open(0), "{"),
+            (TokenKind::Field, "name"),
+            (TokenKind::Punctuation, ":"),
+            (TokenKind::String, "\"Gabriel \\\"Gabe\\\"\""),
+            (TokenKind::Punctuation, ","),
+            (TokenKind::Field, "initial"),
+            (TokenKind::Punctuation, ":"),
+            (TokenKind::Char, "'\\''"),
+            (TokenKind::Punctuation, ","),
+            (TokenKind::Field, "age"),
+            (TokenKind::Punctuation, ":"),
+            (TokenKind::Keyword, "Some"),
+            (TokenKind::Open(1), "("),
+            (TokenKind::Number, "37"),
+            (TokenKind::Close(1), ")"),
+            (TokenKind::Punctuation, ","),
+            (TokenKind::Field, "scores"),
+            (TokenKind::Punctuation, ":"),
+            (TokenKind::Open(1), "["),
+            (TokenKind::Number, "-1.5"),
+            (TokenKind::Punctuation, ","),
+            (TokenKind::Number, "2e-7"),
+            (TokenKind::Close(1), "]"),
+            (TokenKind::Punctuation, ","),
+            (TokenKind::Field, "status"),
+            (TokenKind::Punctuation, ":"),
+            (TokenKind::Keyword, "Ok"),
+            (TokenKind::Open(1), "("),
+            (TokenKind::Keyword, "true"),
+            (TokenKind::Close(1), ")"),
+            (TokenKind::Close(0), "}"),
+        ]
+    );
+    assert_eq!(
+        kinds("std::io::Error"),
+        vec![(TokenKind::TypeName, "std::io::Error")]
+    );
+    assert_eq!(kinds("0xff"), vec![(TokenKind::Number, "0xff")]);
+}
+
+#[test]
+fn test_tokenize_lifetimes() {
+    assert_eq!(
+        kinds("fn f<'a, 'b>"),
+        vec![
+            (TokenKind::Identifier, "fn"),
+            (TokenKind::Identifier, "f"),
+            (TokenKind::Punctuation, "<"),
+            (TokenKind::Lifetime, "'a"),
+            (TokenKind::Punctuation, ","),
+            (TokenKind::Lifetime, "'b"),
+            (TokenKind::Punctuation, ">"),
+        ]
+    );
+    assert_eq!(
+        kinds("['a', '\\n', '\\u{301}', '\\x7f', 'static]"),
+        vec![
+            (TokenKind::Open(0), "["),
+            (TokenKind::Char, "'a'"),
+            (TokenKind::Punctuation, ","),
+            (TokenKind::Char, "'\\n'"),
+            (TokenKind::Punctuation, ","),
+            (TokenKind::Char, "'\\u{301}'"),
+            (TokenKind::Punctuation, ","),
+            (TokenKind::Char, "'\\x7f'"),
+            (TokenKind::Punctuation, ","),
+            (TokenKind::Lifetime, "'static"),
+            (TokenKind::Close(0), "]"),
+        ]
+    );
+}
+
+#[test]
+fn test_highlight() {
+    let text = format!("{:#?}", person());
+    assert_eq!(strip_ansi(highlight(&text)), text);
+    assert_eq!(strip_ansi(highlight_debug(&person())), text);
+    assert!(highlight("Some(1)").starts_with(&fore("Some", Theme::DEFAULT.keyword.into())));
+    assert!(highlight("[[]]").contains(&fore("[", bracket_color(1).into())));
+    assert_ne!(bracket_color(0), bracket_color(1));
+}
+
+#[test]
+fn test_highlighted_macros() {
+    let value = person();
+    assert!(format_dbg!(value).contains(&highlight_debug(&value).replace('\n', "\n    ")));
+    assert_eq!(
+        strip_ansi(indent_objdump!(2, &value)),
+        format!("{value:#?}")
+            .lines()
+            .map(|line| format!("  {line}"))
+            .collect::<Vec<String>>()
+            .join("\n")
+    );
+}
@@ -0,0 +1,71 @@
+mod common;
+
+use debug_et_diagnostics::{
+    SourceLocation, editor_url, file_url, filename, format_location, hyperlink, hyperlinks,
+    location, pad, set_editor_url, set_hyperlinks, step, strip_ansi,
+};
+
+#[test]
+fn test_hyperlink() {
+    assert_eq!(
+        hyperlink("lib.rs:3", "file:///src/lib.rs"),
+        "\x1b]8;;file:///src/lib.rs\x1b\\lib.rs:3\x1b]8;;\x1b\\"
+    );
+    assert_eq!(
+        strip_ansi(hyperlink("lib.rs:3", "file:///src/lib.rs")),
+        "lib.rs:3"
+    );
+    assert_eq!(
+        pad(hyperlink("lib.rs", "file:///src/lib.rs"), 8),
+        format!("{}  ", hyperlink("lib.rs", "file:///src/lib.rs"))
+    );
+}
+
+#[test]
+fn test_location_links() {
+    let _settings = common::settings(
+        || (hyperlinks(), editor_url()),
+        |(enabled, template)| {
+            set_hyperlinks(Some(enabled));
+            set_editor_url(template);
+        },
+    );
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let at = |file| SourceLocation::new("crate", manifest_dir, file, 3, 5);
+    set_editor_url(None);
+    assert_eq!(
+        file_url(&at("src/lib.rs")),
+        format!("file://{manifest_dir}/src/lib.rs")
+    );
+    assert_eq!(
+        file_url(&SourceLocation::new("crate", "/tmp", "src/a b.rs", 3, 5)),
+        "file:///tmp/src/a%20b.rs"
+    );
+    assert_eq!(
+        file_url(&at("/tmp/a b#1?%.rs")),
+        "file:///tmp/a%20b%231%3F%25.rs"
+    );
+
+    set_editor_url(Some("vscode://file/{path}:{line}:{col}".to_string()));
+    assert_eq!(
+        file_url(&at("/src/lib.rs")),
+        "vscode://file//src/lib.rs:3:5"
+    );
+
+    let location = SourceLocation::new("crate", "/", "/src/lib.rs", 3, 5);
+    set_hyperlinks(Some(true));
+    assert!(hyperlinks());
+    let linked = format_location(None, &location);
+    assert!(linked.contains("\x1b]8;;vscode://file//src/lib.rs:3:5\x1b\\"));
+    assert!(filename!().contains("\x1b]8;;vscode://file/"));
+    assert!(location!().contains("\x1b]8;;"));
+    step!("linked");
+
+    set_hyperlinks(Some(false));
+    assert!(!hyperlinks());
+    assert!(!format_location(None, &location).contains("\x1b]8;;"));
+    assert_eq!(
+        strip_ansi(linked),
+        strip_ansi(format_location(None, &location))
+    );
+}
@@ -0,0 +1,64 @@
+mod common;
+
+use debug_et_diagnostics::{
+    Prefixes, format_prefix, in_task, prefixes, set_prefixes, step, strip_ansi, task_id,
+    thread_name,
+};
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut context = Context::from_waker(Waker::noop());
+    let mut future = pin!(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn test_prefixes() {
+    let _settings = common::settings(prefixes, set_prefixes);
+    assert_eq!(prefixes(), Prefixes::NONE);
+    assert_eq!(format_prefix(), "");
+
+    set_prefixes(Prefixes::ALL);
+    let prefix = std::thread::Builder::new()
+        .name("worker".to_string())
+        .spawn(|| {
+            step!("from worker");
+            strip_ansi(format_prefix())
+        })
+        .unwrap()
+        .join()
+        .unwrap();
+    assert_eq!(prefix, format!("pid:{} worker ", std::process::id()));
+
+    let prefix = block_on(in_task(7, async {
+        assert_eq!(task_id(), Some(7));
+        strip_ansi(format_prefix())
+    }));
+    assert_eq!(
+        prefix,
+        format!("pid:{} {} task:7 ", std::process::id(), thread_name())
+    );
+    assert_eq!(task_id(), None);
+
+    set_prefixes(Prefixes {
+        thread: true,
+        ..Prefixes::NONE
+    });
+    let handles = (0..4)
+        .map(|index| {
+            std::thread::spawn(move || {
+                step!(format!("thread {index}"));
+                thread_name()
+            })
+        })
+        .collect::<Vec<_>>();
+    for handle in handles {
+        assert!(handle.join().unwrap().starts_with("thread-"));
+    }
+}
@@ -0,0 +1,128 @@
+use debug_et_diagnostics::{Limits, dbg, format_dbg, format_debug, limit_debug, strip_ansi};
+
+#[derive(Debug)]
+#[allow(dead_code)]
+struct Node {
+    name: &'static str,
+    children: Vec<Node>,
+}
+
+fn tree() -> Node {
+    Node {
+        name: "root",
+        children: vec![
+            Node {
+                name: "left",
+                children: vec![],
+            },
+            Node {
+                name: "right",
+                children: vec![Node {
+                    name: "leaf",
+                    children: vec![],
+                }],
+            },
+        ],
+    }
+}
+
+#[test]
+fn test_no_limits() {
+    let text = format!("{:#?}", tree());
+    assert_eq!(limit_debug(&text, Limits::NONE), text);
+    assert_eq!(
+        limit_debug("[1, [2, 3], \"x\"]", Limits::NONE),
+        "[1, [2, 3], \"x\"]"
+    );
+}
+
+#[test]
+fn test_depth_limit() {
+    let limits = Limits {
+        depth: Some(1),
+        ..Limits::NONE
+    };
+    assert_eq!(
+        limit_debug("[1, [2, 3], Some(4)]", limits),
+        "[1, […] [len=2], Some [len=1]]"
+    );
+    assert_eq!(limit_debug("[1, [], {}]", limits), "[1, [], {}]");
+    assert_eq!(
+        limit_debug(&format!("{:#?}", vec![tree()]), limits),
+        "[\n    Node [len=2],\n]"
+    );
+    assert_eq!(
+        limit_debug(&format!("{:#?}", tree()), limits),
+        "Node {\n    name: \"root\",\n    children: […] [len=2],\n}"
+    );
+    let tokens = (0..40213).collect::<Vec<u32>>();
+    assert_eq!(
+        strip_ansi(format_debug(
+            &tokens,
+            Limits {
+                depth: Some(0),
+                ..Limits::NONE
+            }
+        )),
+        "Vec<u32> [len=40213]"
+    );
+    assert_eq!(
+        strip_ansi(format_debug(
+            &Vec::<u32>::new(),
+            Limits {
+                depth: Some(0),
+                ..Limits::NONE
+            }
+        )),
+        "[]"
+    );
+}
+
+#[test]
+fn test_items_limit() {
+    let limits = Limits {
+        items: Some(2),
+        ..Limits::NONE
+    };
+    assert_eq!(limit_debug("[1, 2, 3, 4, 5]", limits), "[1, 2, … 3 more]");
+    assert_eq!(
+        limit_debug(&format!("{:#?}", vec![1, 2, 3]), limits),
+        "[\n    1,\n    2,\n    … 1 more\n]"
+    );
+    assert_eq!(limit_debug("[1, 2]", limits), "[1, 2]");
+}
+
+#[test]
+fn test_string_limit() {
+    let limits = Limits {
+        string: Some(3),
+        ..Limits::NONE
+    };
+    assert_eq!(
+        limit_debug("[\"abcdef\", \"abc\"]", limits),
+        "[\"abc…\" [len=6], \"abc\"]"
+    );
+}
+
+#[test]
+fn test_lines_limit() {
+    let limits = Limits {
+        lines: Some(2),
+        ..Limits::NONE
+    };
+    assert_eq!(
+        limit_debug(&format!("{:#?}", vec![1, 2, 3]), limits),
+        "[\n    1,\n… 3 more lines"
+    );
+}
+
+#[test]
+fn test_per_call_limits() {
+    let values = (0..100).collect::<Vec<u8>>();
+    let formatted = strip_ansi(format_dbg!(items = 3, values));
+    assert!(formatted.contains("… 97 more"));
+    let formatted = strip_ansi(format_dbg!(depth = 1, items = 1, tree()));
+    assert!(formatted.contains("name: \"root\",\n        … 1 more"));
+    assert_eq!(dbg!(depth = 0, values.len()), 100);
+    assert_eq!(dbg!(lines = 1, values).len(), 100);
+}
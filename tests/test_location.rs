@@ -0,0 +1,106 @@
+mod common;
+
+use debug_et_diagnostics::{
+    LocationStyle, PathStyle, SourceLocation, display_path, filename, format_location, location,
+    location_style, plain_location, registry_crate, set_location_style, source_location,
+    strip_ansi,
+};
+
+const REGISTRY: &str =
+    "/home/user/.cargo/registry/src/index.crates.io-6f17d22bba15001f/serde-json-1.0.140/src/de.rs";
+
+#[test]
+fn test_registry_crate() {
+    assert_eq!(
+        registry_crate(REGISTRY),
+        Some(("serde-json", "1.0.140", "src/de.rs"))
+    );
+    assert_eq!(registry_crate("src/lib.rs"), None);
+    assert_eq!(
+        registry_crate("/.cargo/registry/src/index/foo-2d-0.3.1/src/lib.rs"),
+        Some(("foo-2d", "0.3.1", "src/lib.rs"))
+    );
+    assert_eq!(
+        registry_crate("/.cargo/registry/src/index/x25519-dalek-2.0.0-rc.3/src/lib.rs"),
+        Some(("x25519-dalek", "2.0.0-rc.3", "src/lib.rs"))
+    );
+}
+
+#[test]
+fn test_display_path() {
+    assert_eq!(
+        display_path(REGISTRY, None, PathStyle::WorkspaceRelative),
+        "serde-json@1.0.140/src/de.rs"
+    );
+    assert_eq!(display_path(REGISTRY, None, PathStyle::AsIs), REGISTRY);
+    assert_eq!(display_path(REGISTRY, None, PathStyle::FileOnly), "de.rs");
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let absolute = std::path::Path::new(manifest_dir).join("src/lib.rs");
+    assert_eq!(
+        display_path(
+            absolute.to_str().unwrap(),
+            Some(manifest_dir),
+            PathStyle::WorkspaceRelative
+        ),
+        "src/lib.rs"
+    );
+    assert_eq!(
+        display_path(
+            "/elsewhere/src/lib.rs",
+            Some(manifest_dir),
+            PathStyle::WorkspaceRelative
+        ),
+        "/elsewhere/src/lib.rs"
+    );
+    assert_eq!(
+        display_path(file!(), Some(manifest_dir), PathStyle::WorkspaceRelative),
+        "tests/test_location.rs"
+    );
+}
+
+#[test]
+fn test_location_styles() {
+    let _settings = common::settings(location_style, set_location_style);
+    let location = SourceLocation::new("my-crate", "/workspace/crate", "crate/src/lib.rs", 12, 5);
+    assert_eq!(
+        strip_ansi(format_location(None, &location)),
+        "lib.rs in crate/src:12"
+    );
+    assert_eq!(plain_location(&location), "crate/src/lib.rs:12");
+
+    set_location_style(LocationStyle {
+        path: PathStyle::Clickable,
+        column: false,
+        crate_name: true,
+    });
+    assert_eq!(
+        strip_ansi(format_location(None, &location)),
+        "my-crate › crate/src/lib.rs:12:5"
+    );
+    let (here, line, column) = (source_location!(), line!(), column!());
+    assert_eq!(here.crate_name, Some("debug-et-diagnostics"));
+    assert_eq!(here.manifest_dir, Some(env!("CARGO_MANIFEST_DIR")));
+    assert_eq!(here.file, "tests/test_location.rs");
+    assert_eq!(here.line, line);
+    assert!(here.column < column);
+    assert!(
+        strip_ansi(location!())
+            .starts_with("debug-et-diagnostics › test_location_styles @ tests/test_location.rs:")
+    );
+
+    set_location_style(LocationStyle {
+        path: PathStyle::FileOnly,
+        column: true,
+        crate_name: false,
+    });
+    assert_eq!(strip_ansi(format_location(None, &location)), "lib.rs:12:5");
+    assert_eq!(strip_ansi(filename!()), "test_location.rs");
+
+    set_location_style(LocationStyle {
+        path: PathStyle::AsIs,
+        ..LocationStyle::DEFAULT
+    });
+    assert_eq!(strip_ansi(filename!()), "tests/test_location.rs");
+    set_location_style(LocationStyle::DEFAULT);
+    assert_eq!(strip_ansi(filename!()), "test_location.rs");
+}
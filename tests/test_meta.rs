@@ -0,0 +1,58 @@
+use debug_et_diagnostics::{dbg_meta, format_meta, format_type_name, short_type_name, strip_ansi};
+use std::collections::HashMap;
+
+#[derive(Debug)]
+#[allow(dead_code)]
+struct Token(u8);
+
+#[test]
+fn test_short_type_name() {
+    assert_eq!(
+        short_type_name(std::any::type_name::<Vec<Token>>()),
+        "Vec<Token>"
+    );
+    assert_eq!(
+        short_type_name(std::any::type_name::<HashMap<String, Option<&[u8]>>>()),
+        "HashMap<String, Option<&[u8]>>"
+    );
+    assert_eq!(short_type_name("(u8, i32)"), "(u8, i32)");
+}
+
+#[test]
+fn test_format_type_name() {
+    let formatted = format_type_name("HashMap<String, Vec<u8>>");
+    assert_eq!(strip_ansi(&formatted), "HashMap<String, Vec<u8>>");
+    assert!(formatted.contains(&debug_et_diagnostics::auto("String")));
+    assert!(formatted.contains(&debug_et_diagnostics::auto("u8")));
+}
+
+#[test]
+fn test_format_meta() {
+    let values = vec![Token(1), Token(2)];
+    let meta = strip_ansi(format_meta(&values));
+    assert!(meta.starts_with(&format!(
+        "Vec<Token> size={} align={} @ 0x",
+        size_of::<Vec<Token>>(),
+        align_of::<Vec<Token>>()
+    )));
+    let meta = strip_ansi(format_meta(values.as_slice()));
+    assert!(meta.starts_with("[Token] size=2 align=1 @ "));
+    assert!(meta.ends_with(&format!("{:p}", values.as_ptr())));
+}
+
+#[test]
+fn test_dbg_meta() {
+    let value = 42u64;
+    let text = "text";
+    assert_eq!(dbg_meta!(value), 42);
+    assert_eq!(dbg_meta!(&text), &"text");
+    assert_eq!(dbg_meta!(value, text, [1u16; 3]), (42, "text", [1u16; 3]));
+    let mut calls = 0;
+    let mut next = || {
+        calls += 1;
+        calls
+    };
+    assert_eq!(dbg_meta!(next()), 1);
+    assert_eq!(dbg_meta!(next(), next()), (2, 3));
+    assert_eq!(calls, 3);
+}
@@ -0,0 +1,51 @@
+mod common;
+
+use debug_et_diagnostics::{
+    INDENTATION, branch, columns, dbg, depth, enter, exit, guide_color, indent_lines, indentation,
+    info, scope, set_tree_guides, step, strip_ansi, term_cols, tree_guides, warn,
+};
+
+fn recurse(levels: usize) -> usize {
+    enter!();
+    let depth = if levels == 0 {
+        step!("bottom");
+        info!("at the bottom");
+        depth()
+    } else {
+        recurse(levels - 1)
+    };
+    exit!();
+    depth
+}
+
+#[test]
+fn test_enter_exit() {
+    assert_eq!(depth(), 0);
+    assert_eq!(recurse(2), 3);
+    assert_eq!(depth(), 0);
+    exit!();
+    assert_eq!(depth(), 0);
+}
+
+#[test]
+fn test_indentation() {
+    assert_eq!(indent_lines("a\nb", "--"), "--a\n--b");
+    assert_eq!(guide_color(0), guide_color(6));
+    assert_ne!(guide_color(0), guide_color(1));
+
+    let _settings = common::settings(tree_guides, set_tree_guides);
+    set_tree_guides(false);
+    assert_eq!(indentation(2), " ".repeat(2 * INDENTATION));
+    assert_eq!(branch(1, true), " ".repeat(INDENTATION));
+
+    set_tree_guides(true);
+    assert_eq!(strip_ansi(indentation(2)), "│   │   ");
+    assert_eq!(strip_ansi(branch(1, false)), "│   ├── ");
+    assert_eq!(strip_ansi(branch(1, true)), "│   └── ");
+    {
+        let _scope = scope!();
+        assert_eq!(columns(), term_cols() - INDENTATION);
+        dbg!(depth());
+        warn!("nested");
+    }
+}
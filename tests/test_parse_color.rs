@@ -0,0 +1,149 @@
+use debug_et_diagnostics::parse::check_color_literal;
+use debug_et_diagnostics::{Color, IntoAnsi, color_name, parse_color, rgb_to_byte};
+
+#[test]
+fn test_parse_hex() {
+    assert_eq!(parse_color("#ff8800"), Some(Color::Rgb([255, 136, 0])));
+    assert_eq!(parse_color("#FF8800"), Some(Color::Rgb([255, 136, 0])));
+    assert_eq!(parse_color("#f80"), Some(Color::Rgb([255, 136, 0])));
+    assert_eq!(parse_color("#ff880"), None);
+    assert_eq!(parse_color("#gg8800"), None);
+}
+
+#[test]
+fn test_parse_names() {
+    assert_eq!(parse_color("red"), Some(Color::Rgb([255, 0, 0])));
+    assert_eq!(
+        parse_color("RebeccaPurple"),
+        Some(Color::Rgb([102, 51, 153]))
+    );
+    assert_eq!(
+        parse_color("navajo white"),
+        Some(Color::Rgb([255, 222, 173]))
+    );
+    assert_eq!(
+        parse_color("dark_slate_gray"),
+        Some(Color::Rgb([47, 79, 79]))
+    );
+    assert_eq!(parse_color("not-a-color"), None);
+}
+
+#[test]
+fn test_parse_functions() {
+    assert_eq!(
+        parse_color("rgb(255, 136, 0)"),
+        Some(Color::Rgb([255, 136, 0]))
+    );
+    assert_eq!(
+        parse_color("rgb(255 136 0 / 50%)"),
+        Some(Color::Rgb([255, 136, 0]))
+    );
+    assert_eq!(
+        parse_color("rgb(100%, 0%, 0%)"),
+        Some(Color::Rgb([255, 0, 0]))
+    );
+    assert_eq!(
+        parse_color("hsl(0, 100%, 50%)"),
+        Some(Color::Rgb([255, 0, 0]))
+    );
+    assert_eq!(
+        parse_color("hsl(120deg, 100%, 25%)"),
+        Some(Color::Rgb([0, 128, 0]))
+    );
+    assert_eq!(
+        parse_color("hsl(240, 100%, 50%)"),
+        Some(Color::Rgb([0, 0, 255]))
+    );
+    assert_eq!(parse_color("ansi(160)"), Some(Color::Ansi(160)));
+    assert_eq!(parse_color("ansi(256)"), None);
+    assert_eq!(parse_color("160"), Some(Color::Ansi(160)));
+}
+
+#[test]
+fn test_color_ansi() {
+    assert_eq!(Color::Rgb([255, 136, 0]).ansi(), rgb_to_byte([255, 136, 0]));
+    assert_eq!(Color::Ansi(160).ansi(), 160);
+    assert_eq!(Color::Ansi(196).rgb(), [255, 0, 0]);
+    assert_eq!("#ff8800".parse::<Color>(), Ok(Color::Rgb([255, 136, 0])));
+}
+
+#[test]
+fn test_into_ansi() {
+    assert_eq!(160.into_ansi(), 160);
+    assert_eq!(160usize.into_ansi(), 160);
+    assert_eq!("#ff0000".into_ansi(), 196);
+    assert_eq!("red".into_ansi(), 196);
+    assert_eq!(String::from("ansi(33)").into_ansi(), 33);
+    assert_eq!("300".into_ansi(), 300.into_ansi());
+}
+
+#[test]
+#[should_panic(expected = "invalid color: \"orang\"")]
+fn test_into_ansi_unknown_name() {
+    "orang".into_ansi();
+}
+
+#[test]
+#[should_panic(expected = "invalid color: \"#ff880\"")]
+fn test_into_ansi_malformed_hex() {
+    "#ff880".into_ansi();
+}
+
+#[test]
+#[should_panic(expected = "invalid color: \"rgb(255, 136)\"")]
+fn test_into_ansi_malformed_function() {
+    "rgb(255, 136)".into_ansi();
+}
+
+#[test]
+#[should_panic(expected = "invalid color: -1")]
+fn test_into_ansi_negative() {
+    (-1i32).into_ansi();
+}
+
+#[test]
+fn test_check_color_literal() {
+    check_color_literal(stringify!(220));
+    check_color_literal(stringify!("orange"));
+    check_color_literal(stringify!("Dark Orange"));
+    check_color_literal(stringify!("#ff8800"));
+    check_color_literal(stringify!("300"));
+    check_color_literal(stringify!("ansi(220)"));
+    check_color_literal(stringify!("rgb(255, 136, 0)"));
+    check_color_literal(stringify!("hsl(32deg 100% 50%)"));
+    check_color_literal(stringify!(r#"navy"#));
+}
+
+#[test]
+#[should_panic(expected = "invalid color literal")]
+fn test_check_color_literal_format_string() {
+    check_color_literal(stringify!("hello {}"));
+}
+
+#[test]
+#[should_panic(expected = "invalid color literal")]
+fn test_check_color_literal_typo() {
+    check_color_literal(stringify!("orang"));
+}
+
+#[test]
+#[should_panic(expected = "invalid color literal")]
+fn test_check_color_literal_malformed_function() {
+    check_color_literal(stringify!("ansi(300)"));
+}
+
+#[test]
+fn test_color_name() {
+    assert_eq!(color_name(196), "red");
+    assert_eq!(color_name(16), "black");
+    assert_eq!(color_name(231), "white");
+    assert_eq!(color_name(21), "blue");
+}
+
+#[test]
+fn test_macro_color_arguments() {
+    debug_et_diagnostics::step!(fg = "#ff8800", "text");
+    debug_et_diagnostics::step!(bg = "navy", fg = "ansi(220)", "text");
+    debug_et_diagnostics::step!(fg = 160, "text");
+    debug_et_diagnostics::warn!("orange", "text");
+}
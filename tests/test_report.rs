@@ -0,0 +1,65 @@
+use debug_et_diagnostics::{Report, Severity, strip_ansi};
+
+#[test]
+fn test_report_single_line() {
+    let report = Report::error("unexpected token")
+        .name("input.dsl")
+        .source("let x = = 3;")
+        .label(4..5, "binding starts here")
+        .label(8..9, "expected an expression");
+    assert_eq!(report.severity(), Severity::Error);
+    assert_eq!(report.labels().len(), 2);
+    assert_eq!(
+        strip_ansi(report.render()),
+        [
+            "error: unexpected token",
+            "  ┌─ input.dsl:1:5",
+            "  │",
+            "1 │ let x = = 3;",
+            "  │     ^ binding starts here",
+            "  │         ^ expected an expression",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn test_report_overlapping_and_multi_line() {
+    let source = "fn main() {\n    call(a, b)\n}\n\nfn other() {}";
+    let report = Report::warning("suspicious call")
+        .source(source)
+        .label(16..26, "call")
+        .label(21..25, "arguments")
+        .label(0..29, "function")
+        .label(30..32, "other");
+    assert_eq!(
+        strip_ansi(report.render()),
+        [
+            "warning: suspicious call",
+            "  ┌─ 2:5",
+            "  │",
+            "1 │ fn main() {",
+            "  │ ^^^^^^^^^^^",
+            "2 │     call(a, b)",
+            "  │     ^^^^^^^^^^ call",
+            "  │          ^^^^ arguments",
+            "  │ ^^^^^^^^^^^^^^",
+            "3 │ }",
+            "  │ ^ function",
+            "  ┆",
+            "5 │ fn other() {}",
+            "  │ ^^ other",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn test_report_plain() {
+    let report = Report::info("note").source("abc").label(1..2, "here");
+    let plain = report.render_with_color(false);
+    let colored = report.render_with_color(true);
+    assert_eq!(plain, strip_ansi(&colored));
+    assert_ne!(plain, colored);
+    report.emit();
+}
@@ -0,0 +1,69 @@
+use debug_et_diagnostics::scope::Scope;
+use debug_et_diagnostics::{depth, location, scope};
+
+fn nested(levels: usize) -> usize {
+    let _scope = scope!();
+    if levels == 0 {
+        return depth();
+    }
+    nested(levels - 1)
+}
+
+fn fallible(fail: bool) -> Result<usize, String> {
+    let _scope = scope!();
+    if fail {
+        Err("failed".to_string())?;
+    }
+    Ok(depth())
+}
+
+#[test]
+fn test_scope_depth() {
+    assert_eq!(depth(), 0);
+    {
+        let scope = scope!();
+        assert_eq!(scope.depth(), 0);
+        assert_eq!(depth(), 1);
+        assert!(scope.location().contains("test_scope_depth"));
+    }
+    assert_eq!(depth(), 0);
+    assert_eq!(nested(3), 4);
+    assert_eq!(depth(), 0);
+}
+
+#[test]
+fn test_scope_early_return() {
+    assert_eq!(fallible(false), Ok(1));
+    assert!(fallible(true).is_err());
+    assert_eq!(depth(), 0);
+}
+
+#[test]
+fn test_scope_panicking() {
+    let depths = std::thread::spawn(|| {
+        let result = std::panic::catch_unwind(|| {
+            let _outer = scope!();
+            let _inner = scope!();
+            assert_eq!(depth(), 2);
+            panic!("boom");
+        });
+        assert!(result.is_err());
+        depth()
+    })
+    .join();
+    assert_eq!(depths.ok(), Some(0));
+}
+
+#[test]
+fn test_scope_with_lines() {
+    let lines = ["    value = 1".to_string(), "    other = 2".to_string()];
+    {
+        let scope = Scope::with_lines(location!(), &lines);
+        assert_eq!(scope.depth(), 0);
+        assert_eq!(depth(), 1);
+        let detached = Scope::detached_with_lines(location!(), &lines);
+        assert_eq!(detached.depth(), 1);
+        assert_eq!(depth(), 1);
+    }
+    assert_eq!(depth(), 0);
+}
@@ -0,0 +1,57 @@
+mod common;
+
+use debug_et_diagnostics::{
+    format_snippet, render_snippet, resolve_source, set_source_snippets, snippet, source_snippets,
+    step, strip_ansi, warn,
+};
+
+const SOURCE: &str =
+    "fn main() {\n    let x = 1;\n    let y = 2;\n    step!(x);\n    let z = 3;\n    drop(z);\n}";
+
+#[test]
+fn test_render_snippet() {
+    assert_eq!(
+        strip_ansi(render_snippet(SOURCE, 4, 5).unwrap()),
+        [
+            "  2 │     let x = 1;",
+            "  3 │     let y = 2;",
+            "→ 4 │     step!(x);",
+            "    │     ^",
+            "  5 │     let z = 3;",
+            "  6 │     drop(z);",
+        ]
+        .join("\n")
+    );
+    assert_eq!(
+        strip_ansi(render_snippet(SOURCE, 1, 1).unwrap()),
+        [
+            "→ 1 │ fn main() {",
+            "    │ ^",
+            "  2 │     let x = 1;",
+            "  3 │     let y = 2;",
+        ]
+        .join("\n")
+    );
+    assert_eq!(render_snippet(SOURCE, 0, 1), None);
+    assert_eq!(render_snippet(SOURCE, 8, 1), None);
+}
+
+#[test]
+fn test_snippet() {
+    let snippet = strip_ansi(snippet!().unwrap());
+    assert!(snippet.contains("let snippet = strip_ansi(snippet!().unwrap());"));
+    assert!(snippet.contains("→"));
+    assert!(resolve_source(env!("CARGO_MANIFEST_DIR"), file!()).is_some());
+}
+
+#[test]
+fn test_missing_source() {
+    assert_eq!(
+        format_snippet(env!("CARGO_MANIFEST_DIR"), "src/missing.rs", 1, 1),
+        None
+    );
+    let _settings = common::settings(source_snippets, set_source_snippets);
+    set_source_snippets(true);
+    step!("source snippets enabled");
+    warn!("source snippets enabled");
+}
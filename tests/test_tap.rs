@@ -0,0 +1,41 @@
+use debug_et_diagnostics::{IteratorExt, OptionExt, ResultExt, Tap};
+
+#[derive(Debug, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[test]
+fn test_tap_dbg() {
+    let point = Point { x: 1, y: 2 }.dbg();
+    assert_eq!(point, Point { x: 1, y: 2 });
+    assert_eq!(vec![1, 2, 3].dbg().len(), 3);
+}
+
+#[test]
+fn test_result_dbg_ok() {
+    let result: Result<u8, String> = Ok(3);
+    assert_eq!(result.dbg_ok(), Ok(3));
+    let result: Result<u8, String> = Err("failed".to_string());
+    assert_eq!(result.dbg_ok(), Err("failed".to_string()));
+}
+
+#[test]
+fn test_option_dbg_some() {
+    assert_eq!(Some(Point { x: 0, y: 0 }).dbg_some().map(|p| p.x), Some(0));
+    assert_eq!(None::<u8>.dbg_some(), None);
+}
+
+#[test]
+fn test_dbg_each() {
+    let doubled = [1, 2, 3]
+        .into_iter()
+        .dbg_each()
+        .map(|n| n * 2)
+        .collect::<Vec<i32>>();
+    assert_eq!(doubled, vec![2, 4, 6]);
+    let mut each = std::iter::empty::<u8>().dbg_each();
+    assert_eq!(each.next(), None);
+    assert_eq!(each.next(), None);
+}
@@ -0,0 +1,76 @@
+mod common;
+
+use debug_et_diagnostics::{
+    Timestamps, format_clock, format_timestamp, parse_timestamps, rfc3339, set_timestamps,
+    since_start, step, strip_ansi, timestamp_width, timestamps,
+};
+use std::time::{Duration, UNIX_EPOCH};
+
+#[test]
+fn test_rfc3339() {
+    assert_eq!(rfc3339(UNIX_EPOCH), "1970-01-01T00:00:00.000Z");
+    assert_eq!(
+        rfc3339(UNIX_EPOCH + Duration::from_millis(1_700_000_000_123)),
+        "2023-11-14T22:13:20.123Z"
+    );
+    assert_eq!(
+        rfc3339(UNIX_EPOCH + Duration::from_secs(951_782_400)),
+        "2000-02-29T00:00:00.000Z"
+    );
+    assert_eq!(
+        rfc3339(UNIX_EPOCH - Duration::from_millis(1)),
+        "1969-12-31T23:59:59.999Z"
+    );
+}
+
+#[test]
+fn test_format_clock() {
+    assert_eq!(format_clock(Duration::ZERO), "00:00:00.000");
+    assert_eq!(format_clock(Duration::from_micros(12_345)), "00:00:00.012");
+    assert_eq!(
+        format_clock(Duration::from_millis(3_723_004)),
+        "01:02:03.004"
+    );
+    assert_eq!(format_clock(Duration::from_secs(36_000)), "10:00:00.000");
+    assert_eq!(format_clock(Duration::from_secs(360_000)), "100:00:00.000");
+}
+
+#[test]
+fn test_parse_timestamps() {
+    assert_eq!(parse_timestamps(""), Timestamps::NONE);
+    assert_eq!(parse_timestamps("all"), Timestamps::ALL);
+    assert_eq!(
+        parse_timestamps("Wall, delta"),
+        Timestamps {
+            wall: true,
+            since_start: false,
+            delta: true,
+        }
+    );
+    assert_eq!(
+        parse_timestamps("elapsed,unknown"),
+        Timestamps {
+            wall: false,
+            since_start: true,
+            delta: false,
+        }
+    );
+}
+
+#[test]
+fn test_format_timestamp() {
+    let _settings = common::settings(timestamps, |previous| set_timestamps(Some(previous)));
+    set_timestamps(Some(Timestamps::NONE));
+    assert_eq!(format_timestamp(), "");
+    assert_eq!(timestamp_width(), 0);
+
+    set_timestamps(Some(Timestamps::ALL));
+    assert_eq!(timestamps(), Timestamps::ALL);
+    step!("with timestamps");
+    let timestamp = strip_ansi(format_timestamp());
+    assert_eq!(timestamp.chars().count(), timestamp_width());
+    assert!(timestamp.ends_with(' '));
+    assert!(timestamp.contains('T'));
+    assert!(timestamp.contains('+'));
+    assert!(since_start() > Duration::ZERO);
+}
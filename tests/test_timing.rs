@@ -0,0 +1,64 @@
+mod common;
+
+use debug_et_diagnostics::{
+    Stopwatch, duration_color, humanize_duration, set_step_elapsed, since_last_step, step,
+    step_elapsed, time,
+};
+use std::time::Duration;
+
+#[test]
+fn test_humanize_duration() {
+    assert_eq!(humanize_duration(Duration::from_nanos(850)), "850ns");
+    assert_eq!(humanize_duration(Duration::from_nanos(12_345)), "12.3µs");
+    assert_eq!(humanize_duration(Duration::from_micros(12_345)), "12.3ms");
+    assert_eq!(humanize_duration(Duration::from_millis(1_250)), "1.25s");
+    assert_eq!(humanize_duration(Duration::from_secs(123)), "2m03s");
+    assert_eq!(humanize_duration(Duration::from_secs(3_723)), "1h02m03s");
+}
+
+#[test]
+fn test_duration_color() {
+    assert_eq!(duration_color(Duration::from_nanos(10)), 77);
+    assert_eq!(duration_color(Duration::from_millis(1)), 221);
+    assert_eq!(duration_color(Duration::from_secs(5)), 203);
+}
+
+#[test]
+fn test_stopwatch() {
+    let mut stopwatch = Stopwatch::new("parse");
+    stopwatch.lap("tokenize");
+    stopwatch.lap("build tree");
+    assert_eq!(stopwatch.name(), "parse");
+    assert_eq!(
+        stopwatch
+            .laps()
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<&str>>(),
+        vec!["tokenize", "build tree"]
+    );
+    assert!(stopwatch.laps().iter().map(|(_, d)| *d).sum::<Duration>() <= stopwatch.elapsed());
+    assert_eq!(stopwatch.to_string().lines().count(), 3);
+}
+
+#[test]
+fn test_time() {
+    let value = time!((1..=10).sum::<u32>());
+    assert_eq!(value, 55);
+    assert_eq!(time!(label = "answer", 42), 42);
+}
+
+#[test]
+fn test_since_last_step() {
+    let _settings = common::settings(step_elapsed, set_step_elapsed);
+    std::thread::spawn(|| {
+        assert_eq!(since_last_step(), None);
+        assert!(since_last_step().is_some());
+        set_step_elapsed(true);
+        assert!(step_elapsed());
+        step!("first");
+        step!("second");
+    })
+    .join()
+    .unwrap();
+}
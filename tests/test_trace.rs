@@ -0,0 +1,160 @@
+use debug_et_diagnostics::{depth, trace};
+use std::fmt;
+
+#[derive(Debug)]
+struct Parser {
+    input: String,
+}
+
+impl Parser {
+    #[trace(skip(self))]
+    fn first_word(&self) -> &str {
+        self.input.split_whitespace().next().unwrap_or_default()
+    }
+
+    #[trace(skip(self))]
+    fn input_mut(&mut self) -> &mut String {
+        &mut self.input
+    }
+
+    #[trace]
+    fn parse(&mut self, suffix: &str) -> Result<usize, String> {
+        if suffix.is_empty() {
+            return Err("empty suffix".to_string());
+        }
+        self.input.push_str(suffix);
+        let count = self
+            .input
+            .parse::<usize>()
+            .map_err(|error| error.to_string())?;
+        Ok(count)
+    }
+}
+
+struct Secret;
+
+impl fmt::Display for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "***")
+    }
+}
+
+#[trace(display(secret))]
+fn login(user: &str, secret: Secret) -> bool {
+    let _ = secret;
+    user == "admin"
+}
+
+#[trace]
+fn factorial(n: u64) -> u64 {
+    if n == 0 { 1 } else { n * factorial(n - 1) }
+}
+
+#[trace]
+fn depth_at(levels: usize) -> usize {
+    if levels == 0 {
+        depth()
+    } else {
+        depth_at(levels - 1)
+    }
+}
+
+#[trace(skip_all, skip_return)]
+fn evens(limit: u32) -> impl Iterator<Item = u32> {
+    (0..limit).filter(|n| n % 2 == 0)
+}
+
+#[trace]
+async fn double(value: u32) -> u32 {
+    value * 2
+}
+
+#[trace]
+async fn async_depth() -> usize {
+    depth()
+}
+
+#[trace]
+fn digits(text: &str) -> Result<impl Iterator<Item = u32>, std::num::ParseIntError> {
+    let number = text.parse::<u32>()?;
+    Ok(number
+        .to_string()
+        .chars()
+        .filter_map(|c| c.to_digit(10))
+        .collect::<Vec<u32>>()
+        .into_iter())
+}
+
+#[trace]
+fn first_even(numbers: &[u32]) -> Option<u32> {
+    for number in numbers {
+        if number % 2 == 0 {
+            return Some(*number);
+        }
+    }
+    let last = numbers.last()?;
+    Some(last * 2)
+}
+
+#[trace]
+fn sum((a, b): (u32, u32), _: u32) -> u32 {
+    a + b
+}
+
+/// shadows [std::mem::drop], which the expansion of `#[trace]` must not pick up
+#[allow(dead_code)]
+fn drop(_: ()) {}
+
+fn block_on<F: std::future::Future>(future: F) -> F::Output {
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+#[test]
+fn test_trace_returns_value() {
+    assert_eq!(factorial(4), 24);
+    assert_eq!(depth_at(3), 4);
+    assert_eq!(depth(), 0);
+    assert!(login("admin", Secret));
+    assert_eq!(evens(7).collect::<Vec<u32>>(), vec![0, 2, 4, 6]);
+    assert_eq!(sum((1, 2), 3), 3);
+    assert_eq!(depth(), 0);
+}
+
+#[test]
+fn test_trace_early_returns() {
+    assert_eq!(digits("42").map(Iterator::collect), Ok(vec![4, 2]));
+    assert!(digits("x").is_err());
+    assert_eq!(first_even(&[1, 4, 5]), Some(4));
+    assert_eq!(first_even(&[1, 3]), Some(6));
+    assert_eq!(first_even(&[]), None);
+    assert_eq!(depth(), 0);
+}
+
+#[test]
+fn test_trace_methods() {
+    let mut parser = Parser {
+        input: "4".to_string(),
+    };
+    assert_eq!(parser.first_word(), "4");
+    parser.input_mut().clear();
+    parser.input_mut().push('4');
+    assert_eq!(parser.parse("2"), Ok(42));
+    assert_eq!(parser.parse(""), Err("empty suffix".to_string()));
+    assert!(parser.parse("x").is_err());
+    assert_eq!(depth(), 0);
+}
+
+#[test]
+fn test_trace_async() {
+    assert_eq!(block_on(double(21)), 42);
+    assert_eq!(block_on(async_depth()), 0);
+    assert_eq!(depth(), 0);
+}